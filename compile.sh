@@ -12,6 +12,8 @@ plankCompilerPath="./target/debug/compiler"
 exitEarly=0
 setup=0
 debug=0
+release=0
+maxRecursionDepth=""
 isSafeToCompile=0
 
 example_file_path="./example.plank"
@@ -21,6 +23,8 @@ show_help() {
     echo "    -i, --src <filepath>       Plank source code file to compile"
     echo "    -o, --output <dirpath>     Output dir the executable will be created in"
     echo "    -s, --setup                Build the compiler and setup in this directory"
+    echo "    -r, --release              Disable runtime overflow/division checks in the program"
+    echo "    -m, --max-depth <n>        Stop the program with an error once recursion goes n calls deep"
     echo "    -h, --help                 You already figured this one out"
     echo "Dev flags to play with:"
     echo "    -d, --debug                Enable debug mode for compilation"
@@ -45,6 +49,13 @@ while [ "${1:-}" != '' ]; do
         '-d' | '--debug')
         debug=1
         ;;
+        '-r' | '--release')
+        release=1
+        ;;
+        '-m' | '--max-depth')
+        shift
+        maxRecursionDepth=$1
+        ;;
         '-n' | '--isSafeToCompile')
         isSafeToCompile=1
         ;;
@@ -107,12 +118,18 @@ if [ $exitEarly -eq 1 ]; then
 fi
 
 # Let rust do its thing
-# Pass it src and outputDir, and optionally debug flag
+# Pass it src and outputDir, and optionally debug/release flags
+compilerFlags=""
 if [ $debug -eq 1 ]; then
-    $plankCompilerPath $src $outputDir --debug
-else
-    $plankCompilerPath $src $outputDir
+    compilerFlags="$compilerFlags --debug"
+fi
+if [ $release -eq 1 ]; then
+    compilerFlags="$compilerFlags --no-runtime-checks"
+fi
+if [[ -n "$maxRecursionDepth" ]]; then
+    compilerFlags="$compilerFlags --max-recursion-depth=$maxRecursionDepth"
 fi
+$plankCompilerPath $src $outputDir $compilerFlags
 
 # Ensure that the c source code file was created
 cCodeFile="$outputDir/main.c"
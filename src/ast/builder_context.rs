@@ -1,5 +1,7 @@
 use super::parse_error::ParseError;
 use super::statement::Statement;
+use super::type_table::TypeTable;
+use super::value_hierarchy::DataType;
 use crate::tokenizer::Token;
 use crate::tokenizer::TokenType;
 
@@ -10,15 +12,18 @@ pub struct BuilderContext {
     pub idx: usize,
     pub statements: Vec<Statement>,
     pub errors: Vec<ParseError>,
+    /// From the first pass; needed to tell enum types apart from record types.
+    type_table: TypeTable,
 }
 
 impl BuilderContext {
-    pub fn new(tokens: Vec<Token>) -> Self {
+    pub fn new(tokens: Vec<Token>, type_table: TypeTable) -> Self {
         Self {
             tokens,
             idx: 0,
             statements: Vec::new(),
             errors: Vec::new(),
+            type_table,
         }
     }
 
@@ -67,6 +72,19 @@ impl BuilderContext {
         */
     }
 
+    pub fn type_table(&self) -> &TypeTable {
+        &self.type_table
+    }
+
+    /// Parses the type starting at the current token (see DataType::from_tokens)
+    /// and moves passed it. Doesn't move if it isn't a valid type.
+    pub fn parse_data_type(&mut self) -> Option<DataType> {
+        let (data_type, next_idx) =
+            DataType::from_tokens(&self.tokens, self.idx, &self.type_table)?;
+        self.idx = next_idx;
+        Some(data_type)
+    }
+
     pub fn peek_next(&self) -> Option<&Token> {
         if self.idx + 1 < self.tokens.len() {
             Some(&self.tokens[self.idx + 1])
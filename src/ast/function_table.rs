@@ -1,4 +1,4 @@
-use super::value_hierarchy::DataType;
+use super::value_hierarchy::{DataType, Logical};
 use std::collections::{HashMap, hash_map::Values};
 
 // Function-related structures
@@ -18,13 +18,40 @@ pub struct FunctionSymbol {
 pub struct Parameter {
     pub name: String,
     pub data_type: DataType,
+    /// Passed in when a call leaves the argument out (String greeting = "hi").
+    /// Record fields never have one.
+    pub default: Option<Logical>,
 }
 
-/// Function table for managing function symbols
+impl FunctionSymbol {
+    /// How the function looks in plank, for error messages (ex. area(Number, Number)).
+    pub fn to_plank_str(&self) -> String {
+        let parameter_types: Vec<String> = self
+            .parameters
+            .iter()
+            .map(|param| param.data_type.to_plank_str())
+            .collect();
+        format!("{}({})", self.identifier, parameter_types.join(", "))
+    }
+
+    pub fn has_parameter_types(&self, types: &[DataType]) -> bool {
+        self.parameters.len() == types.len()
+            && self
+                .parameters
+                .iter()
+                .zip(types)
+                .all(|(param, data_type)| param.data_type == *data_type)
+    }
+}
+
+/// Function table for managing function symbols.
+/// Functions can be overloaded, so a name can have more than one function,
+/// as long as their parameter types are different.
 #[derive(Debug, Clone)]
 pub struct FunctionTable {
     functions: HashMap<u8, FunctionSymbol>,
-    name_to_id: HashMap<String, u8>,
+    /// Every overload of a name, in the order they were declared.
+    name_to_ids: HashMap<String, Vec<u8>>,
     next_id: u8,
 }
 
@@ -32,13 +59,14 @@ impl FunctionTable {
     pub fn new() -> Self {
         Self {
             functions: HashMap::new(),
-            name_to_id: HashMap::new(),
+            name_to_ids: HashMap::new(),
             next_id: 0,
         }
     }
 
     /// Inserts a new function into the table and returns the assigned key.
-    /// Returns None if the table is full or if a function with the same name already exists.
+    /// Returns None if the table is full or if a function with the same name
+    /// and parameter types already exists.
     pub fn insert(
         &mut self,
         name: &str,
@@ -46,8 +74,12 @@ impl FunctionTable {
         return_type: DataType,
         line: &u32,
     ) -> Option<u8> {
-        // Check for duplicate names
-        if self.name_to_id.contains_key(name) {
+        // Check for duplicate overloads
+        let parameter_types: Vec<DataType> = parameters
+            .iter()
+            .map(|param| param.data_type.clone())
+            .collect();
+        if self.get_func_def(name, &parameter_types).is_some() {
             return None; // Function already exists
         }
 
@@ -65,7 +97,10 @@ impl FunctionTable {
         };
 
         self.functions.insert(id, function_symbol);
-        self.name_to_id.insert(name.to_string(), id);
+        self.name_to_ids
+            .entry(name.to_string())
+            .or_default()
+            .push(id);
         self.next_id = self.next_id.wrapping_add(1);
 
         Some(id)
@@ -76,30 +111,56 @@ impl FunctionTable {
         self.functions.get(&id)
     }
 
-    /// Gets function ID by name
-    pub fn get_id_with_function_name(&self, name: &str) -> Option<u8> {
-        self.name_to_id.get(name).copied()
+    /// Gets the ID of the overload of name with exactly these parameter types.
+    pub fn get_id(&self, name: &str, parameter_types: &[DataType]) -> Option<u8> {
+        self.name_to_ids
+            .get(name)?
+            .iter()
+            .copied()
+            .find(|id| self.functions[id].has_parameter_types(parameter_types))
     }
 
-    pub fn get_func_def_using_str(&self, function_name: &String) -> Option<&FunctionSymbol> {
-        let id_optional = self.get_id_with_function_name(function_name);
-        match id_optional {
-            None => {
-                println!(
-                    "HEY: the id lookup for the function named {} failed.",
-                    function_name
-                );
-                return None;
-            }
-            _ => (),
+    /// Gets the overload of name with exactly these parameter types.
+    pub fn get_func_def(
+        &self,
+        name: &str,
+        parameter_types: &[DataType],
+    ) -> Option<&FunctionSymbol> {
+        let id = self.get_id(name, parameter_types)?;
+        self.get_using_id(id)
+    }
+
+    /// Every overload of the function, in the order they were declared.
+    pub fn get_overloads(&self, name: &str) -> Vec<&FunctionSymbol> {
+        match self.name_to_ids.get(name) {
+            Some(ids) => ids.iter().map(|id| &self.functions[id]).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// The name the function has in the generated c code. An overloaded
+    /// function has its parameter types added to its name, since c doesn't
+    /// have overloading (ex. area(Number, Number) => plank_area_Number_Number).
+    pub fn get_c_name(&self, function_def: &FunctionSymbol) -> String {
+        if self.get_overloads(&function_def.identifier).len() < 2 {
+            return function_def.identifier.clone();
+        }
+
+        let mut c_name = format!("plank_{}", function_def.identifier);
+        for param in &function_def.parameters {
+            c_name.push('_');
+            // List<Number> => List_Number
+            let type_str: String = (param.data_type.to_plank_str().chars())
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect();
+            c_name.push_str(type_str.trim_end_matches('_'));
         }
-        let function_def = self.get_using_id(id_optional.unwrap());
-        function_def
+        c_name
     }
 
     /// Checks if a function with the given name exists
     pub fn contains_name(&self, name: &str) -> bool {
-        self.name_to_id.contains_key(name)
+        self.name_to_ids.contains_key(name)
     }
 
     /// Returns the number of functions in the table
@@ -3,18 +3,22 @@ mod function_table;
 mod parse_error;
 mod pure_builder;
 mod statement;
+mod type_table;
 mod value_hierarchy;
 
 // Public facing API of AST module - using new pure builder
-pub use pure_builder::build_ast;
+pub use pure_builder::{build_ast, parse_default_value};
 
 pub use statement::*; // all statement types
 
 pub use function_table::{FunctionSymbol, FunctionTable, Parameter};
 pub use parse_error::ParseError;
+pub use type_table::{EnumSymbol, RecordSymbol, TypeTable};
 pub use value_hierarchy::GeneralOperator;
 pub use value_hierarchy::{
-    Comparison, ComparisonOperator, DataType, Expression, ExpressionOperator, Logical,
-    LogicalOperator, Term, TermOperator, Unary, Value, ValueType, convert_comparison_op_to_general,
+    Accessor, AccessorKind, Comparison, ComparisonOperator, DataType, Expression,
+    ExpressionOperator, Literal, Logical, LogicalOperator, Power, Term, TermOperator, Unary,
+    UnaryOperator, Value, ValueType, convert_comparison_op_to_general,
     convert_expression_op_to_general, convert_logical_op_to_general, convert_term_op_to_general,
+    convert_unary_op_to_general,
 };
@@ -44,12 +44,26 @@ pub enum ParseError {
         line: u32,
         function_name: String,
     },
+    UnterminatedRecordDeclaration {
+        line: u32,
+        record_name: String,
+    },
+    UnterminatedEnumDeclaration {
+        line: u32,
+        enum_name: String,
+    },
     UnterminatedIfStatement {
         line: u32,
     },
     UnterminatedWhileStatement {
         line: u32,
     },
+    UnterminatedForStatement {
+        line: u32,
+    },
+    UnterminatedMatchStatement {
+        line: u32,
+    },
 }
 
 impl ParseError {
@@ -65,8 +79,12 @@ impl ParseError {
             ParseError::MissingColon { line, .. } => *line,
             ParseError::MissingDelimiter { line, .. } => *line,
             ParseError::UnterminatedFunctionDeclaration { line, .. } => *line,
+            ParseError::UnterminatedRecordDeclaration { line, .. } => *line,
+            ParseError::UnterminatedEnumDeclaration { line, .. } => *line,
             ParseError::UnterminatedIfStatement { line } => *line,
             ParseError::UnterminatedWhileStatement { line } => *line,
+            ParseError::UnterminatedForStatement { line } => *line,
+            ParseError::UnterminatedMatchStatement { line } => *line,
         }
     }
 
@@ -110,10 +128,12 @@ impl ParseError {
                     format_token_error(data_type)
                 );
                 eprintln!(
-                    "  {} Valid types are: {}, {}",
+                    "  {} Valid types are: {}, {}, {}, {}",
                     error_line_end(),
                     format_token("Number"),
-                    format_token("String")
+                    format_token("String"),
+                    format_token("Boolean"),
+                    format_token("List<type>")
                 );
             }
             ParseError::InvalidReturnType { line, return_type } => {
@@ -124,10 +144,12 @@ impl ParseError {
                     format_token_error(return_type)
                 );
                 eprintln!(
-                    "  {} Valid return types are: {}, {}, or {}",
+                    "  {} Valid return types are: {}, {}, {}, {}, or {}",
                     error_line_end(),
                     format_token("Number"),
                     format_token("String"),
+                    format_token("Boolean"),
+                    format_token("List<type>"),
                     //format_token("Void"),
                     format_token("nothing")
                 );
@@ -195,6 +217,22 @@ impl ParseError {
                     format_name(function_name)
                 );
             }
+            ParseError::UnterminatedRecordDeclaration { line, record_name } => {
+                error_header("Unterminated record declaration", *line);
+                eprintln!(
+                    "  {} Record {} is missing closing 'endRecord'",
+                    error_line_start(),
+                    format_name(record_name)
+                );
+            }
+            ParseError::UnterminatedEnumDeclaration { line, enum_name } => {
+                error_header("Unterminated enum declaration", *line);
+                eprintln!(
+                    "  {} Enum {} is missing closing 'endEnum'",
+                    error_line_start(),
+                    format_name(enum_name)
+                );
+            }
             ParseError::UnterminatedIfStatement { line } => {
                 error_header("Unterminated if statement", *line);
                 eprintln!(
@@ -209,6 +247,20 @@ impl ParseError {
                     error_line_start()
                 );
             }
+            ParseError::UnterminatedForStatement { line } => {
+                error_header("Unterminated for statement", *line);
+                eprintln!(
+                    "  {} For statement is missing closing 'EndFor'",
+                    error_line_start()
+                );
+            }
+            ParseError::UnterminatedMatchStatement { line } => {
+                error_header("Unterminated match statement", *line);
+                eprintln!(
+                    "  {} Match statement is missing closing 'EndMatch'",
+                    error_line_start()
+                );
+            }
         }
     }
 }
@@ -1,17 +1,21 @@
 use super::builder_context::BuilderContext;
 use super::parse_error::ParseError;
 use super::statement::{
-    FunctionDeclarationStatement, IfStatement, PrintStatement, ReturnStatement, Statement,
-    VariableDeclarationStatement, WhileStatement,
+    BreakStatement, ConditionalArm, ContinueStatement, EnumDeclarationStatement, ForStatement,
+    FunctionDeclarationStatement, IfStatement, MatchCase, MatchStatement, PrintStatement,
+    RecordDeclarationStatement, ReturnStatement, Statement, VariableDeclarationStatement,
+    WhileStatement,
 };
 
 use crate::ast::value_hierarchy::{
-    Comparison, DataType, Expression, Logical, Term, Unary, Value, ValueType,
-    convert_token_type_to_comparison_op, convert_token_type_to_expression_op,
+    Accessor, AccessorKind, Comparison, DataType, Expression, Logical, Power, Term, Unary, Value,
+    ValueType, convert_token_type_to_comparison_op, convert_token_type_to_expression_op,
     convert_token_type_to_logical_op, convert_token_type_to_term_op,
+    convert_token_type_to_unary_op,
 };
+use crate::ast::{Parameter, TypeTable};
 use crate::ast::{RawFunctionCallStatement, VariableAssignmentStatement};
-use crate::tokenizer::{Token, TokenType};
+use crate::tokenizer::{Token, TokenType, tokenize_str};
 
 /// Helper function to create an invalid statement - used when parsing fails
 fn create_invalid_statement() -> Statement {
@@ -22,6 +26,7 @@ fn create_invalid_statement() -> Statement {
         //assigned_value: Value::invalid(),
         //assigned_expr: Expression::new(),
         assigned_logical: Logical::new(),
+        is_const: false,
     })
 }
 
@@ -52,11 +57,50 @@ macro_rules! expect_token {
 }
 
 /// Build AST from tokens - pure structural parsing, no validation
-pub fn build_ast(tokens: Vec<Token>) -> BuilderContext {
-    let context = BuilderContext::new(tokens);
+pub fn build_ast(tokens: Vec<Token>, type_table: &TypeTable) -> BuilderContext {
+    let context = BuilderContext::new(tokens, type_table.clone());
     parse_program(context)
 }
 
+/// Parses the default value of a parameter for the first pass, starting at
+/// idx (just passed the '='). Returns the index of the ',' or ')' after it.
+/// Problems with the default are reported when the builder gets to the
+/// function, so anything that doesn't parse cleanly is just None.
+pub fn parse_default_value(
+    tokens: &[Token],
+    mut idx: usize,
+    type_table: &TypeTable,
+) -> (Option<Logical>, usize) {
+    let start = idx;
+    let mut depth = 0;
+    while idx < tokens.len() {
+        match tokens[idx].token_type {
+            TokenType::LeftParen | TokenType::LeftBracket => depth += 1,
+            TokenType::RightParen | TokenType::RightBracket if depth > 0 => depth -= 1,
+            TokenType::Comma | TokenType::RightParen if depth == 0 => break,
+            TokenType::Returns | TokenType::Colon | TokenType::EOF => break,
+            _ => (),
+        }
+        idx += 1;
+    }
+
+    let mut default_tokens = tokens[start..idx].to_vec();
+    default_tokens.push(Token {
+        lexeme: String::new(),
+        token_type: TokenType::EOF,
+        line_number: tokens[idx.min(tokens.len() - 1)].line_number,
+        col_number: 0,
+    });
+
+    let context = BuilderContext::new(default_tokens, type_table.clone());
+    let (logical, context) = parse_logical(context);
+    if !context.errors.is_empty() || !context.is_at_end() || start == idx {
+        return (None, idx);
+    }
+
+    (Some(logical), idx)
+}
+
 fn parse_program(mut context: BuilderContext) -> BuilderContext {
     while !context.is_at_end() {
         let (stmt, returned_context) = parse_statement(context);
@@ -79,20 +123,42 @@ fn parse_statement(mut context: BuilderContext) -> (Option<Statement>, BuilderCo
             let (stmt, ctx) = parse_variable_declaration(context);
             (Some(stmt), ctx)
         }
+        TokenType::Let => {
+            let (stmt, ctx) = parse_let_declaration(context);
+            (Some(stmt), ctx)
+        }
+        TokenType::Const => {
+            let (stmt, ctx) = parse_const_declaration(context);
+            (Some(stmt), ctx)
+        }
         TokenType::FunctionDeclaration => {
             let (stmt, ctx) = parse_function_declaration(context);
             (Some(stmt), ctx)
         }
+        TokenType::Record => {
+            let (stmt, ctx) = parse_record_declaration(context);
+            (Some(stmt), ctx)
+        }
+        TokenType::Enum => {
+            let (stmt, ctx) = parse_enum_declaration(context);
+            (Some(stmt), ctx)
+        }
         TokenType::Return => {
             let (stmt, ctx) = parse_return_statement(context);
             (Some(stmt), ctx)
         }
-        TokenType::Print | TokenType::Println => {
+        TokenType::Print | TokenType::Println | TokenType::Eprint | TokenType::Eprintln => {
             let (stmt, ctx) = parse_print_statement(context);
             (Some(stmt), ctx)
         }
         TokenType::Identity => {
-            if is_token_beginning_of_a_raw_function_call(&context) {
+            // A record name followed by a variable name (Point p: ...)
+            let is_record_variable_declaration = matches!(context.peek_next(), Some(token) if token.token_type == TokenType::Identity);
+
+            if is_record_variable_declaration {
+                let (stmt, ctx) = parse_variable_declaration(context);
+                (Some(stmt), ctx)
+            } else if is_token_beginning_of_a_raw_function_call(&context) {
                 let (stmt, ctx) = parse_raw_function_call_stmt(context);
                 (Some(stmt), ctx)
             } else {
@@ -108,6 +174,18 @@ fn parse_statement(mut context: BuilderContext) -> (Option<Statement>, BuilderCo
             let (stmt, ctx) = parse_while_stmt(context);
             (Some(stmt), ctx)
         }
+        TokenType::For => {
+            let (stmt, ctx) = parse_for_stmt(context);
+            (Some(stmt), ctx)
+        }
+        TokenType::Match => {
+            let (stmt, ctx) = parse_match_stmt(context);
+            (Some(stmt), ctx)
+        }
+        TokenType::Break | TokenType::Continue => {
+            let (stmt, ctx) = parse_loop_control_stmt(context);
+            (Some(stmt), ctx)
+        }
         TokenType::EOF => {
             context.advance(); // move passed EOF token
             (None, context)
@@ -127,12 +205,10 @@ fn parse_statement(mut context: BuilderContext) -> (Option<Statement>, BuilderCo
 }
 
 fn parse_variable_declaration(mut context: BuilderContext) -> (Statement, BuilderContext) {
-    // Parse data type
-    let data_type = match context.get_curr().lexeme.as_str() {
-        "Number" => DataType::Number,
-        "String" => DataType::String,
-        "Boolean" => DataType::Boolean,
-        _ => {
+    // Parse data type (moves passed it)
+    let data_type = match context.parse_data_type() {
+        Some(data_type) => data_type,
+        None => {
             context.handle_parse_error(ParseError::InvalidDataType {
                 line: context.get_curr().line_number,
                 data_type: context.get_curr().lexeme.clone(),
@@ -140,8 +216,23 @@ fn parse_variable_declaration(mut context: BuilderContext) -> (Statement, Builde
             return (create_invalid_statement(), context);
         }
     };
-    context.advance();
 
+    parse_variable_declaration_after_type(context, data_type)
+}
+
+/// let x: 5; is a variable declaration whose type is worked out from its value
+/// in the analyzer (until then, it's Unknown).
+fn parse_let_declaration(mut context: BuilderContext) -> (Statement, BuilderContext) {
+    context.advance(); // Skip "let" keyword
+
+    parse_variable_declaration_after_type(context, DataType::Unknown)
+}
+
+/// The name, ':', value and ';' of a variable declaration.
+fn parse_variable_declaration_after_type(
+    mut context: BuilderContext,
+    data_type: DataType,
+) -> (Statement, BuilderContext) {
     // Parse identifier
     expect_token!(
         context,
@@ -180,11 +271,28 @@ fn parse_variable_declaration(mut context: BuilderContext) -> (Statement, Builde
         //assigned_value: value,
         //assigned_expr: expr,
         assigned_logical: logical,
+        is_const: false,
     });
 
     (statement, context)
 }
 
+/// const Number MAX: 10; is a variable declaration with 'const' in front
+/// (so is const let MAX: 10;).
+fn parse_const_declaration(mut context: BuilderContext) -> (Statement, BuilderContext) {
+    context.advance(); // Skip "const" keyword
+
+    let (mut statement, context) = match context.get_curr().token_type {
+        TokenType::Let => parse_let_declaration(context),
+        _ => parse_variable_declaration(context),
+    };
+    if let Statement::VariableDeclaration(var_decl) = &mut statement {
+        var_decl.is_const = true;
+    }
+
+    (statement, context)
+}
+
 fn parse_expression(mut context: BuilderContext) -> (Expression, BuilderContext) {
     let mut expr = Expression::new();
     let (term1, returned_context) = parse_term(context);
@@ -219,7 +327,7 @@ fn parse_term(mut context: BuilderContext) -> (Term, BuilderContext) {
     while !context.is_at_end()
         && matches!(
             context.get_curr().token_type,
-            TokenType::Asterisk | TokenType::Slash
+            TokenType::Asterisk | TokenType::Slash | TokenType::Percent
         )
     {
         let op = convert_token_type_to_term_op(context.get_curr().token_type);
@@ -237,32 +345,71 @@ fn parse_term(mut context: BuilderContext) -> (Term, BuilderContext) {
 fn parse_unary(mut context: BuilderContext) -> (Unary, BuilderContext) {
     let mut operation = None;
 
-    // Check for unary operators (+ or -)
-    if !context.is_at_end()
-        && matches!(
-            context.get_curr().token_type,
-            TokenType::Plus | TokenType::Minus
-        )
-    {
-        operation = Some(convert_token_type_to_expression_op(
-            context.get_curr().token_type,
-        ));
+    // Check for unary operators (+, -, not/!)
+    if is_unary_operator(&context) {
+        operation = convert_token_type_to_unary_op(context.get_curr().token_type);
         context.advance();
+
+        // The operand can have its own prefix operator (- -a, not not b).
+        if is_unary_operator(&context) {
+            let (operand, returned_context) = parse_unary(context);
+            let mut power = Power::new();
+            power.values.push(Value::from_unary(operand));
+            let unary = Unary {
+                operation,
+                power,
+                data_type: DataType::Unknown,
+            };
+            return (unary, returned_context);
+        }
     }
 
-    // Parse the primary value
-    let (primary, returned_context) = parse_value(context);
+    let (power, returned_context) = parse_power(context);
     context = returned_context;
 
     let unary = Unary {
         operation,
-        primary,
+        power,
         data_type: DataType::Unknown,
     };
 
     (unary, context)
 }
 
+fn is_unary_operator(context: &BuilderContext) -> bool {
+    !context.is_at_end()
+        && matches!(
+            context.get_curr().token_type,
+            TokenType::Plus | TokenType::Minus | TokenType::Bang
+        )
+}
+
+fn parse_power(mut context: BuilderContext) -> (Power, BuilderContext) {
+    let mut power = Power::new();
+    let (value1, returned_context) = parse_value(context);
+    context = returned_context;
+    power.values.push(value1);
+
+    while !context.is_at_end() && context.get_curr().token_type == TokenType::DoubleAsterisk {
+        context.advance();
+
+        // The exponent can have a prefix operator (2 ** -x). It takes the rest
+        // of the chain with it, which keeps ** right associative.
+        if is_unary_operator(&context) {
+            let (exponent, returned_context2) = parse_unary(context);
+            context = returned_context2;
+            power.values.push(Value::from_unary(exponent));
+            break;
+        }
+
+        let (value2, returned_context2) = parse_value(context);
+        context = returned_context2;
+        power.values.push(value2);
+    }
+
+    (power, context)
+}
+
 fn parse_logical(mut context: BuilderContext) -> (Logical, BuilderContext) {
     //println!("Entered parse_logical: {:#?} ", context.get_curr());
     let mut logical = Logical::new();
@@ -353,6 +500,25 @@ fn parse_identity_assignment_statement(mut context: BuilderContext) -> (Statemen
     let line_number = context.get_curr().line_number;
     context.advance();
 
+    // Assigning to an element of a list or a field of a record (ps[i].x <= ...)
+    let mut accessors = Vec::new();
+    if !context.is_at_end()
+        && matches!(
+            context.get_curr().token_type,
+            TokenType::LeftBracket | TokenType::Dot
+        )
+    {
+        let (parsed_accessors, returned_context) = parse_accessors(context);
+        context = returned_context;
+        accessors = parsed_accessors;
+
+        expect_token!(
+            context,
+            TokenType::LessThanEqualTo,
+            "Expected '<=' after list index or record field"
+        );
+    }
+
     // TODO: confirm that this token is <= (assignment token)
     // For now, just advance assuming it's an assignment operator
     if context.is_at_end() {
@@ -375,12 +541,14 @@ fn parse_identity_assignment_statement(mut context: BuilderContext) -> (Statemen
 
     let assignent_struct = Statement::VariableAssignment(VariableAssignmentStatement {
         var_name: identity_lexeme,
+        accessors,
         var_data_type: DataType::Unknown, // unknown until semantic analysis
         //assigned_value: val,
         //assigned_expr: expr,
         assigned_logical: logical,
         line_var_was_declared_on: 0, // unknown until semantic analysis
         line_number,
+        is_global: false, // unknown until semantic analysis
     });
 
     (assignent_struct, context)
@@ -400,12 +568,34 @@ fn parse_function_declaration(mut context: BuilderContext) -> (Statement, Builde
     let line_declared_on = context.get_curr().line_number;
     context.advance();
 
-    // Skip to return type; we already have the function header in the function map.
-    // TODO: put the function header in this struct; would make semantics alot easier
-    // to just look in the struct than doing a lookup to a map. But this might be naive,
-    // not sure what downsides there'd be.
+    // The rest of the header is already in the function map, but the parameter
+    // types are needed to tell which overload this is. Anything that doesn't
+    // look like a parameter is skipped, like in the first pass.
+    let mut parameters = Vec::new();
     while !context.is_at_end() && context.get_curr().token_type != TokenType::Returns {
-        context.advance();
+        match context.parse_data_type() {
+            Some(data_type) if context.get_curr().token_type == TokenType::Identity => {
+                let name = context.get_curr().lexeme.clone();
+                context.advance();
+
+                // String greeting = "hi"
+                let mut default = None;
+                if context.get_curr().token_type == TokenType::Equal {
+                    context.advance();
+                    let (logical, ctx) = parse_logical(context);
+                    context = ctx;
+                    default = Some(logical);
+                }
+
+                parameters.push(Parameter {
+                    name,
+                    data_type,
+                    default,
+                });
+            }
+            Some(_) => (),
+            None => context.advance(),
+        }
     }
 
     if context.is_at_end() {
@@ -428,26 +618,24 @@ fn parse_function_declaration(mut context: BuilderContext) -> (Statement, Builde
         return (create_invalid_statement(), context);
     }
 
-    let return_type_lexeme: &str = &context.get_curr().lexeme;
-    let return_type = match return_type_lexeme {
-        "Number" => DataType::Number,
-        "String" => DataType::String,
-        "Void" => DataType::Void,
-        "Boolean" => DataType::Boolean,
-        "nothing" => DataType::Void,
-        _ => {
-            context.handle_parse_error(ParseError::InvalidReturnType {
-                line: context.get_curr().line_number,
-                return_type: return_type_lexeme.to_string(),
-            });
-            return (create_invalid_statement(), context);
+    let return_type = match context.get_curr().lexeme.as_str() {
+        "Void" | "nothing" => {
+            context.advance();
+            DataType::Void
         }
+        // Moves passed the return type
+        _ => match context.parse_data_type() {
+            Some(data_type) => data_type,
+            None => {
+                context.handle_parse_error(ParseError::InvalidReturnType {
+                    line: context.get_curr().line_number,
+                    return_type: context.get_curr().lexeme.clone(),
+                });
+                return (create_invalid_statement(), context);
+            }
+        },
     };
 
-    if !context.is_at_end() {
-        context.advance(); // Skip return type (we've already confirmed its there)
-    }
-
     expect_token!(
         context,
         TokenType::Colon,
@@ -488,6 +676,7 @@ fn parse_function_declaration(mut context: BuilderContext) -> (Statement, Builde
     // Return the complete function declaration with body
     let statement = Statement::FunctionDeclaration(FunctionDeclarationStatement {
         function_name,
+        parameters,
         line_declared_on,
         return_type,
         body,
@@ -496,6 +685,130 @@ fn parse_function_declaration(mut context: BuilderContext) -> (Statement, Builde
     (statement, context)
 }
 
+fn parse_record_declaration(mut context: BuilderContext) -> (Statement, BuilderContext) {
+    let start_line = context.get_curr().line_number;
+    context.advance(); // Skip "record" keyword
+
+    expect_token!(
+        context,
+        TokenType::Identity,
+        "Expected record name after 'record'"
+    );
+    let record_name = context.get_curr().lexeme.clone();
+    let line_declared_on = context.get_curr().line_number;
+    context.advance();
+
+    expect_token!(context, TokenType::Colon, "Expected ':' after record name");
+    context.advance();
+
+    // Number x;
+    let mut fields = Vec::new();
+    while !context.is_at_end() && context.get_curr().token_type != TokenType::EndRecord {
+        let data_type = match context.parse_data_type() {
+            Some(data_type) => data_type,
+            None => {
+                context.handle_parse_error(ParseError::InvalidDataType {
+                    line: context.get_curr().line_number,
+                    data_type: context.get_curr().lexeme.clone(),
+                });
+                return (create_invalid_statement(), context);
+            }
+        };
+
+        expect_token!(
+            context,
+            TokenType::Identity,
+            "Expected field name after data type"
+        );
+        let name = context.get_curr().lexeme.clone();
+        context.advance();
+
+        expect_token!(
+            context,
+            TokenType::Semicolon,
+            "Expected semicolon after record field"
+        );
+        context.advance();
+
+        fields.push(Parameter {
+            name,
+            data_type,
+            default: None,
+        });
+    }
+
+    if context.is_at_end() {
+        context.handle_parse_error(ParseError::UnterminatedRecordDeclaration {
+            line: start_line,
+            record_name,
+        });
+        return (create_invalid_statement(), context);
+    }
+
+    context.advance(); // Skip endRecord
+
+    let statement = Statement::RecordDeclaration(RecordDeclarationStatement {
+        record_name,
+        line_declared_on,
+        fields,
+    });
+
+    (statement, context)
+}
+
+fn parse_enum_declaration(mut context: BuilderContext) -> (Statement, BuilderContext) {
+    let start_line = context.get_curr().line_number;
+    context.advance(); // Skip "enum" keyword
+
+    expect_token!(
+        context,
+        TokenType::Identity,
+        "Expected enum name after 'enum'"
+    );
+    let enum_name = context.get_curr().lexeme.clone();
+    let line_declared_on = context.get_curr().line_number;
+    context.advance();
+
+    expect_token!(context, TokenType::Colon, "Expected ':' after enum name");
+    context.advance();
+
+    // Red, Green, Blue
+    let mut variants = Vec::new();
+    loop {
+        expect_token!(context, TokenType::Identity, "Expected enum variant name");
+        variants.push(context.get_curr().lexeme.clone());
+        context.advance();
+
+        if context.get_curr().token_type != TokenType::Comma {
+            break;
+        }
+        context.advance();
+    }
+
+    if context.is_at_end() {
+        context.handle_parse_error(ParseError::UnterminatedEnumDeclaration {
+            line: start_line,
+            enum_name,
+        });
+        return (create_invalid_statement(), context);
+    }
+
+    expect_token!(
+        context,
+        TokenType::EndEnum,
+        "Expected ',' or 'endEnum' after enum variant"
+    );
+    context.advance();
+
+    let statement = Statement::EnumDeclaration(EnumDeclarationStatement {
+        enum_name,
+        line_declared_on,
+        variants,
+    });
+
+    (statement, context)
+}
+
 fn parse_return_statement(mut context: BuilderContext) -> (Statement, BuilderContext) {
     let line_declared_on = context.get_curr().line_number;
     context.advance(); // Skip "return" keyword
@@ -523,7 +836,14 @@ fn parse_return_statement(mut context: BuilderContext) -> (Statement, BuilderCon
 
 fn parse_print_statement(mut context: BuilderContext) -> (Statement, BuilderContext) {
     let line = context.get_curr().line_number;
-    let is_print_ln = context.get_curr().token_type == TokenType::Println;
+    let is_print_ln = matches!(
+        context.get_curr().token_type,
+        TokenType::Println | TokenType::Eprintln
+    );
+    let to_stderr = matches!(
+        context.get_curr().token_type,
+        TokenType::Eprint | TokenType::Eprintln
+    );
 
     context.advance(); // skip print
 
@@ -534,7 +854,28 @@ fn parse_print_statement(mut context: BuilderContext) -> (Statement, BuilderCont
     );
     context.advance(); // skip print
 
-    let (logical, mut context) = parse_logical(context);
+    // print(a, b, c) prints every argument, with a space (or the named
+    // separator argument) between them.
+    let (arguments, argument_names, mut context) = parse_arguments(context);
+    let mut logicals = Vec::new();
+    let mut separator = None;
+    let mut positional_after_named = None;
+    for (idx, (argument, name)) in arguments.into_iter().zip(argument_names).enumerate() {
+        match name.as_deref() {
+            None => {
+                if separator.is_some() && positional_after_named.is_none() {
+                    positional_after_named = Some(idx + 1);
+                }
+                logicals.push(argument)
+            }
+            Some("separator") if separator.is_none() => separator = Some(argument),
+            Some(name) => context.errors.push(ParseError::UnexpectedToken {
+                line,
+                expected: "a single 'separator' (the only named argument of print)".to_string(),
+                found: name.to_string(),
+            }),
+        }
+    }
 
     expect_token!(
         context,
@@ -552,9 +893,11 @@ fn parse_print_statement(mut context: BuilderContext) -> (Statement, BuilderCont
 
     let statement = Statement::Print(PrintStatement {
         line,
-        //expression: expr,
-        logical,
+        logicals,
+        separator,
+        positional_after_named,
         is_print_ln,
+        to_stderr,
     });
 
     (statement, context)
@@ -573,17 +916,31 @@ fn parse_value(mut context: BuilderContext) -> (Value, BuilderContext) {
             ValueType::InlineNumber,
             token.lexeme.clone(),
         ),
-        TokenType::Str => Value::new(
-            DataType::String,
-            ValueType::InlineString,
-            token.lexeme.clone(),
-        ),
+        TokenType::Str => {
+            let (value, ctx) = parse_string(context, &token);
+            context = ctx;
+            value
+        }
         TokenType::True | TokenType::False => Value::new(
             // boolean coverage
             DataType::Boolean,
             ValueType::InlineBoolean,
             token.lexeme.clone(),
         ),
+        TokenType::LeftBracket => {
+            // List literal: [1, 2, 3]
+            context.advance(); // move passed [
+            let (elements, ctx) = parse_comma_separated_logicals(context, TokenType::RightBracket);
+            context = ctx;
+            // Context moves passed closing bracket at bottom of function.
+
+            Value::new_with_params(
+                DataType::Unknown,
+                ValueType::ListLiteral,
+                token.lexeme.clone(),
+                elements,
+            )
+        }
         TokenType::Identity => {
             /*
              * if just variable name (no parameters), treat as variable.
@@ -611,17 +968,30 @@ fn parse_value(mut context: BuilderContext) -> (Value, BuilderContext) {
                     context.advance();
                     context.advance(); // move passed (
 
-                    let (params, ctx) = parse_function_call_parameters(context);
+                    let (params, argument_names, ctx) = parse_arguments(context);
                     context = ctx;
                     // Context moves passed closing paren after this match; at bottom
                     // of function we advance.
 
-                    Value::new_with_params(
+                    let mut value = Value::new_with_params(
                         DataType::Unknown,
                         ValueType::FunctionCall,
                         raw_text,
                         params,
-                    )
+                    );
+                    value.argument_names = argument_names;
+                    value
+                } else if matches!(
+                    next_token.token_type,
+                    TokenType::LeftBracket | TokenType::Dot
+                ) {
+                    // Indexing into a list or getting a field of a record: ps[i].x
+                    let raw_text = context.get_curr().lexeme.clone();
+                    context.advance();
+
+                    let (accessors, ctx) = parse_accessors(context);
+                    // Already passed the last accessor, don't advance again.
+                    return (Value::new_with_accessors(raw_text, accessors), ctx);
                 } else {
                     // create Value as a variable call
                     Value::new(DataType::Unknown, ValueType::Variable, token.lexeme.clone())
@@ -639,14 +1009,114 @@ fn parse_value(mut context: BuilderContext) -> (Value, BuilderContext) {
     (value, context)
 }
 
-// Called when a function call is found, gathers all expressions specified in a function
-// calls parameters.
-fn parse_function_call_parameters(mut context: BuilderContext) -> (Vec<Logical>, BuilderContext) {
+// A string with {} in it ("i is {i}") is interpolated; the code in each {} is
+// parsed like any other value. {{ and }} are a { and } in the text.
+fn parse_string(mut context: BuilderContext, token: &Token) -> (Value, BuilderContext) {
+    let line = token.line_number;
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut is_interpolated = false;
+
+    let mut chars = token.lexeme.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                is_interpolated = true;
+                let mut code = String::new();
+                let mut is_closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        is_closed = true;
+                        break;
+                    }
+                    code.push(c);
+                }
+                // The rest of the statement is still parsed, so these errors are
+                // added without skipping to the next statement.
+                if !is_closed {
+                    context.errors.push(ParseError::MissingDelimiter {
+                        line,
+                        delimiter: "}".to_string(),
+                        context: "string interpolation".to_string(),
+                    });
+                    break;
+                }
+
+                let text_value = Value::new(
+                    DataType::String,
+                    ValueType::InlineString,
+                    std::mem::take(&mut text),
+                );
+                pieces.push(Logical::from_value(text_value));
+
+                let hole_context =
+                    BuilderContext::new(tokenize_str(&code, line), context.type_table().clone());
+                if hole_context.is_at_end() {
+                    context.errors.push(ParseError::UnexpectedToken {
+                        line,
+                        expected: "a value inside of {}".to_string(),
+                        found: "}".to_string(),
+                    });
+                    continue;
+                }
+                let (hole, hole_context) = parse_logical(hole_context);
+                context.errors.extend(hole_context.errors.iter().cloned());
+                if !hole_context.is_at_end() {
+                    context.errors.push(ParseError::UnexpectedToken {
+                        line,
+                        expected: "'}' to end the interpolated value".to_string(),
+                        found: hole_context.get_curr().lexeme.clone(),
+                    });
+                }
+                pieces.push(hole);
+            }
+            _ => text.push(c),
+        }
+    }
+
+    if !is_interpolated {
+        return (
+            Value::new(DataType::String, ValueType::InlineString, text),
+            context,
+        );
+    }
+
+    if !text.is_empty() {
+        pieces.push(Logical::from_value(Value::new(
+            DataType::String,
+            ValueType::InlineString,
+            text,
+        )));
+    }
+    let value = Value::new_with_params(
+        DataType::String,
+        ValueType::InterpolatedString,
+        token.lexeme.clone(),
+        pieces,
+    );
+    (value, context)
+}
+
+// Called when a function call or list literal is found, gathers all expressions
+// specified in a function calls parameters (or the elements of the list), up
+// until the closing token.
+fn parse_comma_separated_logicals(
+    mut context: BuilderContext,
+    closing_token: TokenType,
+) -> (Vec<Logical>, BuilderContext) {
     // so it'll be expression comma expression etc....
 
     let mut passed_expressions: Vec<Logical> = Vec::new();
 
-    while !context.is_at_end() && context.get_curr().token_type != TokenType::RightParen {
+    while !context.is_at_end() && context.get_curr().token_type != closing_token {
         //let (expr, ctx) = parse_expression(context);
         let (expr, ctx) = parse_logical(context);
         context = ctx;
@@ -659,7 +1129,7 @@ fn parse_function_call_parameters(mut context: BuilderContext) -> (Vec<Logical>,
 
         if context.get_curr().token_type == TokenType::Comma {
             context.advance();
-        } else if context.get_curr().token_type != TokenType::RightParen {
+        } else if context.get_curr().token_type != closing_token {
             // Expected comma or closing paren
             // For now, just break out and let the calling function handle the error
             break;
@@ -678,41 +1148,133 @@ fn parse_function_call_parameters(mut context: BuilderContext) -> (Vec<Logical>,
     (passed_expressions, context)
 }
 
+// Like parse_comma_separated_logicals, but for the arguments of a function
+// call, which can be given by name (greet(name: "x")). Also returns the name
+// of each argument, or None if it's passed by position.
+fn parse_arguments(
+    mut context: BuilderContext,
+) -> (Vec<Logical>, Vec<Option<String>>, BuilderContext) {
+    let mut arguments = Vec::new();
+    let mut argument_names = Vec::new();
+
+    while !context.is_at_end() && context.get_curr().token_type != TokenType::RightParen {
+        let is_named = context.get_curr().token_type == TokenType::Identity
+            && matches!(context.peek_next(), Some(token) if token.token_type == TokenType::Colon);
+        if is_named {
+            argument_names.push(Some(context.get_curr().lexeme.clone()));
+            context.advance();
+            context.advance(); // move passed :
+        } else {
+            argument_names.push(None);
+        }
+
+        let (argument, ctx) = parse_logical(context);
+        context = ctx;
+        arguments.push(argument);
+
+        if context.is_at_end() || context.get_curr().token_type != TokenType::Comma {
+            // Missing closing paren is left for the calling function.
+            break;
+        }
+        context.advance();
+    }
+
+    (arguments, argument_names, context)
+}
+
+// Called when a '[' or '.' follows a variable's name. Gathers every index
+// ([i]) and field (.x) in order, and leaves the context passed the last one.
+// Like parse_comma_separated_logicals, a missing ']' or field name is left for
+// the calling function to run into.
+fn parse_accessors(mut context: BuilderContext) -> (Vec<Accessor>, BuilderContext) {
+    let mut accessors = Vec::new();
+
+    while !context.is_at_end() {
+        match context.get_curr().token_type {
+            TokenType::LeftBracket => {
+                context.advance(); // move passed [
+                let (index, ctx) = parse_logical(context);
+                context = ctx;
+                accessors.push(Accessor::new(AccessorKind::Index(index)));
+
+                if context.is_at_end() || context.get_curr().token_type != TokenType::RightBracket {
+                    break;
+                }
+                context.advance(); // move passed ]
+            }
+            TokenType::Dot => {
+                context.advance(); // move passed .
+                if context.is_at_end() || context.get_curr().token_type != TokenType::Identity {
+                    break;
+                }
+                let field_name = context.get_curr().lexeme.clone();
+                accessors.push(Accessor::new(AccessorKind::Field(field_name)));
+                context.advance();
+            }
+            _ => break,
+        }
+    }
+
+    (accessors, context)
+}
+
 fn parse_if_stmt(mut context: BuilderContext) -> (Statement, BuilderContext) {
     let start_line = context.get_curr().line_number;
-    context.advance(); // Skip "if" keyword
 
-    expect_token!(context, TokenType::LeftParen, "Expected '(' after 'if'");
-    context.advance();
+    // The 'if' and every 'elseIf' after it are parsed the same way.
+    let mut arms = Vec::new();
+    loop {
+        let arm_line = context.get_curr().line_number;
+        context.advance(); // Skip "if"/"elseIf" keyword
+
+        expect_token!(
+            context,
+            TokenType::LeftParen,
+            "Expected '(' after 'if'/'elseIf'"
+        );
+        context.advance();
 
-    let (condition_logical, mut context) = parse_logical(context);
+        let (condition_logical, returned_context) = parse_logical(context);
+        context = returned_context;
 
-    expect_token!(
-        context,
-        TokenType::RightParen,
-        "Expected ')' after if condition"
-    );
-    context.advance();
+        expect_token!(
+            context,
+            TokenType::RightParen,
+            "Expected ')' after if condition"
+        );
+        context.advance();
 
-    expect_token!(context, TokenType::Colon, "Expected ':' after if condition");
-    context.advance();
+        expect_token!(context, TokenType::Colon, "Expected ':' after if condition");
+        context.advance();
 
-    let mut if_body = Vec::new();
-    while !context.is_at_end()
-        && context.get_curr().token_type != TokenType::Else
-        && context.get_curr().token_type != TokenType::EndIf
-    {
-        let start_idx = context.idx;
-        let (stmt, returned_context) = parse_statement(context);
-        context = returned_context;
+        let mut body = Vec::new();
+        while !context.is_at_end()
+            && context.get_curr().token_type != TokenType::Else
+            && context.get_curr().token_type != TokenType::ElseIf
+            && context.get_curr().token_type != TokenType::EndIf
+        {
+            let start_idx = context.idx;
+            let (stmt, returned_context) = parse_statement(context);
+            context = returned_context;
 
-        if let Some(statement) = stmt {
-            if_body.push(statement);
+            if let Some(statement) = stmt {
+                body.push(statement);
+            }
+
+            // Prevent infinite loop if parse_statement doesn't advance
+            if context.idx == start_idx {
+                context.advance();
+            }
         }
 
-        // Prevent infinite loop if parse_statement doesn't advance
-        if context.idx == start_idx {
-            context.advance();
+        arms.push(ConditionalArm {
+            line_declared_on: arm_line,
+            condition: condition_logical,
+            body,
+        });
+
+        if context.is_at_end() || context.get_curr().token_type != TokenType::ElseIf {
+            break;
         }
     }
 
@@ -749,8 +1311,7 @@ fn parse_if_stmt(mut context: BuilderContext) -> (Statement, BuilderContext) {
 
     let statement = Statement::If(IfStatement {
         line_declared_on: start_line,
-        condition: condition_logical,
-        if_body,
+        arms,
         else_body: if !else_body.is_empty() {
             Some(else_body)
         } else {
@@ -810,11 +1371,213 @@ fn parse_while_stmt(mut context: BuilderContext) -> (Statement, BuilderContext)
         line_declared_on: start_line,
         condition: condition_logical,
         body,
+        exit_label: None, // set during semantic analysis if needed
+    });
+
+    (statement, context)
+}
+
+fn parse_for_stmt(mut context: BuilderContext) -> (Statement, BuilderContext) {
+    let start_line = context.get_curr().line_number;
+    context.advance(); // Skip for
+
+    expect_token!(
+        context,
+        TokenType::Identity,
+        "Expected loop variable name after 'for'"
+    );
+    let var_name = context.get_curr().lexeme.clone();
+    context.advance();
+
+    expect_token!(context, TokenType::In, "Expected 'in' after loop variable");
+    context.advance();
+
+    let (start, mut context) = parse_logical(context);
+
+    expect_token!(
+        context,
+        TokenType::DotDot,
+        "Expected '..' between start and end of range"
+    );
+    context.advance();
+
+    let (end, mut context) = parse_logical(context);
+
+    let mut step = None;
+    if !context.is_at_end() && context.get_curr().token_type == TokenType::Step {
+        context.advance();
+        let (step_logical, returned_context) = parse_logical(context);
+        context = returned_context;
+        step = Some(step_logical);
+    }
+
+    expect_token!(context, TokenType::Colon, "Expected ':' after for range");
+    context.advance();
+
+    let mut body = Vec::new();
+    while !context.is_at_end() && context.get_curr().token_type != TokenType::EndFor {
+        let start_idx = context.idx;
+        let (stmt, returned_context) = parse_statement(context);
+        context = returned_context;
+
+        if let Some(statement) = stmt {
+            body.push(statement);
+        }
+
+        if context.idx == start_idx {
+            context.advance();
+        }
+    }
+
+    if context.is_at_end() {
+        context.handle_parse_error(ParseError::UnterminatedForStatement { line: start_line });
+        return (create_invalid_statement(), context);
+    }
+
+    context.advance();
+
+    let statement = Statement::For(ForStatement {
+        line_declared_on: start_line,
+        var_name,
+        start,
+        end,
+        step,
+        body,
+        exit_label: None, // set during semantic analysis if needed
     });
 
     (statement, context)
 }
 
+fn parse_match_stmt(mut context: BuilderContext) -> (Statement, BuilderContext) {
+    let start_line = context.get_curr().line_number;
+    context.advance(); // Skip match
+
+    expect_token!(context, TokenType::LeftParen, "Expected '(' after 'match'");
+    context.advance();
+
+    let (scrutinee, mut context) = parse_logical(context);
+
+    expect_token!(
+        context,
+        TokenType::RightParen,
+        "Expected ')' after match value"
+    );
+    context.advance();
+
+    expect_token!(context, TokenType::Colon, "Expected ':' after match value");
+    context.advance();
+
+    let mut cases = Vec::new();
+    let mut default_body = None;
+    while !context.is_at_end() && context.get_curr().token_type != TokenType::EndMatch {
+        expect_token!(
+            context,
+            TokenType::Case | TokenType::Default,
+            "Expected 'case' or 'default' in match statement"
+        );
+        let case_line = context.get_curr().line_number;
+        let is_default = context.get_curr().token_type == TokenType::Default;
+        context.advance();
+
+        // case 1, 2, 3:
+        let mut values = Vec::new();
+        if !is_default {
+            loop {
+                let (value, returned_context) = parse_logical(context);
+                context = returned_context;
+                values.push(value);
+
+                if context.is_at_end() || context.get_curr().token_type != TokenType::Comma {
+                    break;
+                }
+                context.advance();
+            }
+        }
+
+        expect_token!(context, TokenType::Colon, "Expected ':' after case/default");
+        context.advance();
+
+        let mut body = Vec::new();
+        while !context.is_at_end()
+            && !matches!(
+                context.get_curr().token_type,
+                TokenType::Case | TokenType::Default | TokenType::EndMatch
+            )
+        {
+            let start_idx = context.idx;
+            let (stmt, returned_context) = parse_statement(context);
+            context = returned_context;
+
+            if let Some(statement) = stmt {
+                body.push(statement);
+            }
+
+            if context.idx == start_idx {
+                context.advance();
+            }
+        }
+
+        if !is_default {
+            cases.push(MatchCase {
+                line_declared_on: case_line,
+                values,
+                body,
+            });
+        } else if default_body.is_none() {
+            default_body = Some(body);
+        } else {
+            context.handle_parse_error(ParseError::UnexpectedToken {
+                line: case_line,
+                expected: "only one 'default' in match statement".to_string(),
+                found: "default".to_string(),
+            });
+            return (create_invalid_statement(), context);
+        }
+    }
+
+    if context.is_at_end() {
+        context.handle_parse_error(ParseError::UnterminatedMatchStatement { line: start_line });
+        return (create_invalid_statement(), context);
+    }
+
+    context.advance();
+
+    let statement = Statement::Match(MatchStatement {
+        line_declared_on: start_line,
+        scrutinee,
+        cases,
+        default_body,
+    });
+
+    (statement, context)
+}
+
+/// break; and continue; are just the keyword and a semicolon.
+fn parse_loop_control_stmt(mut context: BuilderContext) -> (Statement, BuilderContext) {
+    let line = context.get_curr().line_number;
+    let is_break = context.get_curr().token_type == TokenType::Break;
+    context.advance();
+
+    expect_token!(
+        context,
+        TokenType::Semicolon,
+        "Expected semicolon after break/continue"
+    );
+    context.advance();
+
+    let statement = if is_break {
+        Statement::Break(BreakStatement {
+            line,
+            exit_label: None, // set during semantic analysis if needed
+        })
+    } else {
+        Statement::Continue(ContinueStatement { line })
+    };
+
+    (statement, context)
+}
+
 fn is_token_beginning_of_a_raw_function_call(ctxt: &BuilderContext) -> bool {
     match ctxt.peek_next() {
         Some(token) => {
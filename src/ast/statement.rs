@@ -1,7 +1,8 @@
 //use crate::comparison::Logical;
 use crate::ast::DataType;
+use crate::ast::Parameter;
 use crate::ast::Value;
-use crate::ast::value_hierarchy::{Expression, Logical};
+use crate::ast::value_hierarchy::{Accessor, AccessorKind, Expression, Logical};
 
 #[derive(Debug)]
 pub enum Statement {
@@ -13,6 +14,106 @@ pub enum Statement {
     Print(PrintStatement),
     If(IfStatement),
     While(WhileStatement),
+    For(ForStatement),
+    Break(BreakStatement),
+    Continue(ContinueStatement),
+    Match(MatchStatement),
+    RecordDeclaration(RecordDeclarationStatement),
+    EnumDeclaration(EnumDeclarationStatement),
+}
+
+impl Statement {
+    /// The line this statement starts on.
+    pub fn line(&self) -> u32 {
+        match self {
+            Statement::VariableDeclaration(stmt) => stmt.line_declared_on,
+            Statement::VariableAssignment(stmt) => stmt.line_number,
+            Statement::FunctionDeclaration(stmt) => stmt.line_declared_on,
+            Statement::RawFunctionCall(stmt) => stmt.line,
+            Statement::Return(stmt) => stmt.line_declared_on,
+            Statement::Print(stmt) => stmt.line,
+            Statement::If(stmt) => stmt.line_declared_on,
+            Statement::While(stmt) => stmt.line_declared_on,
+            Statement::For(stmt) => stmt.line_declared_on,
+            Statement::Break(stmt) => stmt.line,
+            Statement::Continue(stmt) => stmt.line,
+            Statement::Match(stmt) => stmt.line_declared_on,
+            Statement::RecordDeclaration(stmt) => stmt.line_declared_on,
+            Statement::EnumDeclaration(stmt) => stmt.line_declared_on,
+        }
+    }
+
+    /// Calls visit on every value in this statement itself, but not on the
+    /// ones in the blocks inside of it (see blocks).
+    pub fn visit_values<'a>(&'a self, visit: &mut impl FnMut(&'a Value)) {
+        let logicals: Vec<&Logical> = match self {
+            Statement::VariableDeclaration(stmt) => vec![&stmt.assigned_logical],
+            Statement::VariableAssignment(stmt) => {
+                let indexes = stmt
+                    .accessors
+                    .iter()
+                    .filter_map(|accessor| match &accessor.kind {
+                        AccessorKind::Index(index) => Some(index),
+                        AccessorKind::Field(_) => None,
+                    });
+                indexes.chain([&stmt.assigned_logical]).collect()
+            }
+            Statement::RawFunctionCall(stmt) => {
+                stmt.value.visit_values(visit);
+                Vec::new()
+            }
+            Statement::Return(stmt) => vec![&stmt.return_value],
+            Statement::Print(stmt) => stmt.logicals.iter().chain(&stmt.separator).collect(),
+            Statement::If(stmt) => stmt.arms.iter().map(|arm| &arm.condition).collect(),
+            Statement::While(stmt) => vec![&stmt.condition],
+            Statement::For(stmt) => [&stmt.start, &stmt.end]
+                .into_iter()
+                .chain(&stmt.step)
+                .collect(),
+            Statement::Match(stmt) => {
+                let case_values = stmt.cases.iter().flat_map(|case| &case.values);
+                [&stmt.scrutinee].into_iter().chain(case_values).collect()
+            }
+            Statement::FunctionDeclaration(_)
+            | Statement::Break(_)
+            | Statement::Continue(_)
+            | Statement::RecordDeclaration(_)
+            | Statement::EnumDeclaration(_) => Vec::new(),
+        };
+
+        for logical in logicals {
+            logical.visit_values(visit);
+        }
+    }
+
+    /// The blocks of statements inside of this one, in order. A function's
+    /// body isn't one of them, since it doesn't run where it's declared.
+    pub fn blocks(&self) -> Vec<&[Statement]> {
+        match self {
+            Statement::If(stmt) => {
+                let arm_bodies = stmt.arms.iter().map(|arm| arm.body.as_slice());
+                arm_bodies.chain(stmt.else_body.as_deref()).collect()
+            }
+            Statement::While(stmt) => vec![&stmt.body],
+            Statement::For(stmt) => vec![&stmt.body],
+            Statement::Match(stmt) => {
+                let case_bodies = stmt.cases.iter().map(|case| case.body.as_slice());
+                case_bodies.chain(stmt.default_body.as_deref()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Calls visit on every statement, and every statement in the blocks inside
+/// of them (but not inside of function bodies).
+pub fn visit_statements<'a>(statements: &'a [Statement], visit: &mut impl FnMut(&'a Statement)) {
+    for statement in statements {
+        visit(statement);
+        for block in statement.blocks() {
+            visit_statements(block, visit);
+        }
+    }
 }
 
 /**
@@ -26,25 +127,60 @@ pub struct VariableDeclarationStatement {
     pub data_type: DataType,
     pub line_declared_on: u32,
     pub assigned_logical: Logical, // we can have bools now :)
+    /// const Number MAX: 10; can't be assigned to, and is visible in every function.
+    pub is_const: bool,
 }
 
 #[derive(Debug)]
 pub struct VariableAssignmentStatement {
     pub var_name: String,
+    /// ps[i].x <= ...; => [[i], .x]. Empty when assigning to the variable itself.
+    pub accessors: Vec<Accessor>,
     pub var_data_type: DataType,
     pub assigned_logical: Logical,
     pub line_var_was_declared_on: u32,
     pub line_number: u32,
+    /// Assigning to a global, set in semantic analysis.
+    pub is_global: bool,
 }
 
 #[derive(Debug)]
 pub struct FunctionDeclarationStatement {
     pub function_name: String,
+    pub parameters: Vec<Parameter>,
     pub return_type: DataType,
     pub line_declared_on: u32,
     pub body: Vec<Statement>,
 }
 
+impl FunctionDeclarationStatement {
+    /// Tells apart the overloads of a function (see FunctionTable::get_func_def).
+    pub fn parameter_types(&self) -> Vec<DataType> {
+        self.parameters
+            .iter()
+            .map(|param| param.data_type.clone())
+            .collect()
+    }
+}
+
+/// The fields are also gathered into the TypeTable in the first pass, so the
+/// record can be used before (above) where it's declared.
+#[derive(Debug)]
+pub struct RecordDeclarationStatement {
+    pub record_name: String,
+    pub line_declared_on: u32,
+    pub fields: Vec<Parameter>,
+}
+
+/// enum Color: Red, Green, Blue endEnum
+/// Also gathered into the TypeTable in the first pass, like records.
+#[derive(Debug)]
+pub struct EnumDeclarationStatement {
+    pub enum_name: String,
+    pub line_declared_on: u32,
+    pub variants: Vec<String>,
+}
+
 /// This is pretty much a wrapper struct so you can just call a function without
 /// assigning it to anything. Thus, its just a Value, since Values encompass
 /// function calls.
@@ -63,21 +199,84 @@ pub struct ReturnStatement {
 #[derive(Debug)]
 pub struct PrintStatement {
     pub line: u32,
-    pub logical: Logical,
+    pub logicals: Vec<Logical>,
+    /// Printed between the logicals; a space when not given.
+    pub separator: Option<Logical>,
+    /// Position (from 1) of the first unnamed argument after the separator,
+    /// which is an error like it is for any other call.
+    pub positional_after_named: Option<usize>,
     pub is_print_ln: bool,
+    /// eprint and eprintln write to stderr instead of stdout.
+    pub to_stderr: bool,
 }
 
+/// if (cond): ... elseIf (cond): ... else: ... endIf
+/// The first arm is the 'if', and every arm after it is an 'elseIf'.
 #[derive(Debug)]
 pub struct IfStatement {
     pub line_declared_on: u32,
-    pub condition: Logical,
-    pub if_body: Vec<Statement>,
+    pub arms: Vec<ConditionalArm>,
     pub else_body: Option<Vec<Statement>>,
 }
 
+#[derive(Debug)]
+pub struct ConditionalArm {
+    pub line_declared_on: u32,
+    pub condition: Logical,
+    pub body: Vec<Statement>,
+}
+
 #[derive(Debug)]
 pub struct WhileStatement {
     pub line_declared_on: u32,
     pub condition: Logical,
     pub body: Vec<Statement>,
+    /// Set by the analyzer when a break needs to jump past the end of this
+    /// loop with a goto (see BreakStatement).
+    pub exit_label: Option<String>,
+}
+
+/// for var_name in start..end step step:
+/// The range doesn't include end, and step defaults to 1 when not given.
+#[derive(Debug)]
+pub struct ForStatement {
+    pub line_declared_on: u32,
+    pub var_name: String,
+    pub start: Logical,
+    pub end: Logical,
+    pub step: Option<Logical>,
+    pub body: Vec<Statement>,
+    pub exit_label: Option<String>,
+}
+
+/// A Number match is a c switch, and a c break inside of a switch only leaves
+/// the switch. When this break is inside of one, the analyzer sets exit_label
+/// to the label placed after the loop, and we goto it instead.
+#[derive(Debug)]
+pub struct BreakStatement {
+    pub line: u32,
+    pub exit_label: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ContinueStatement {
+    pub line: u32,
+}
+
+/// match (scrutinee): case 1, 2: ... default: ... endMatch
+/// Only Numbers and Strings can be matched on, and every case value has to be
+/// a literal.
+#[derive(Debug)]
+pub struct MatchStatement {
+    pub line_declared_on: u32,
+    pub scrutinee: Logical,
+    pub cases: Vec<MatchCase>,
+    pub default_body: Option<Vec<Statement>>,
+}
+
+#[derive(Debug)]
+pub struct MatchCase {
+    pub line_declared_on: u32,
+    pub values: Vec<Logical>,
+    pub body: Vec<Statement>,
 }
@@ -0,0 +1,105 @@
+use super::function_table::Parameter;
+use super::value_hierarchy::DataType;
+
+#[derive(Debug, Clone)]
+pub struct RecordSymbol {
+    pub identifier: String,
+    // Fields are written just like parameters (type, then name)
+    pub fields: Vec<Parameter>,
+    pub line_declared_on: u32,
+}
+
+impl RecordSymbol {
+    pub fn get_field_type(&self, field_name: &str) -> Option<&DataType> {
+        self.fields
+            .iter()
+            .find(|field| field.name == field_name)
+            .map(|field| &field.data_type)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumSymbol {
+    pub identifier: String,
+    pub variants: Vec<String>,
+    pub line_declared_on: u32,
+}
+
+impl EnumSymbol {
+    pub fn contains_variant(&self, variant: &str) -> bool {
+        self.variants.iter().any(|existing| existing == variant)
+    }
+}
+
+/// User defined types (records and enums) declared in a plank file.
+/// Kept in the order they're declared in.
+#[derive(Debug, Clone)]
+pub struct TypeTable {
+    records: Vec<RecordSymbol>,
+    enums: Vec<EnumSymbol>,
+}
+
+impl TypeTable {
+    pub fn new() -> Self {
+        Self {
+            records: Vec::new(),
+            enums: Vec::new(),
+        }
+    }
+
+    /// Returns false (and doesn't insert) if a type with the same name
+    /// already exists.
+    pub fn insert_record(&mut self, record: RecordSymbol) -> bool {
+        if self.get_line_declared_on(&record.identifier).is_some() {
+            return false;
+        }
+        self.records.push(record);
+        true
+    }
+
+    /// Returns false (and doesn't insert) if a type with the same name
+    /// already exists.
+    pub fn insert_enum(&mut self, enum_symbol: EnumSymbol) -> bool {
+        if self.get_line_declared_on(&enum_symbol.identifier).is_some() {
+            return false;
+        }
+        self.enums.push(enum_symbol);
+        true
+    }
+
+    /// Line of the record or enum with this name, if there is one.
+    pub fn get_line_declared_on(&self, name: &str) -> Option<u32> {
+        match self.get_record(name) {
+            Some(record) => Some(record.line_declared_on),
+            None => self
+                .get_enum(name)
+                .map(|enum_symbol| enum_symbol.line_declared_on),
+        }
+    }
+
+    pub fn get_record(&self, name: &str) -> Option<&RecordSymbol> {
+        self.records.iter().find(|record| record.identifier == name)
+    }
+
+    pub fn contains_record(&self, name: &str) -> bool {
+        self.get_record(name).is_some()
+    }
+
+    pub fn get_all_records(&self) -> &[RecordSymbol] {
+        &self.records
+    }
+
+    pub fn get_enum(&self, name: &str) -> Option<&EnumSymbol> {
+        self.enums
+            .iter()
+            .find(|enum_symbol| enum_symbol.identifier == name)
+    }
+
+    pub fn contains_enum(&self, name: &str) -> bool {
+        self.get_enum(name).is_some()
+    }
+
+    pub fn get_all_enums(&self) -> &[EnumSymbol] {
+        &self.enums
+    }
+}
@@ -1,4 +1,5 @@
-use crate::tokenizer::TokenType;
+use super::type_table::TypeTable;
+use crate::tokenizer::{Token, TokenType};
 
 /// This is a lazy solution to a specific problem.
 /// All of the operators in different grammars in the "value hierarchy" are
@@ -7,7 +8,7 @@ use crate::tokenizer::TokenType;
 /// It'd be really helpful if we could store all of the operators across an
 /// entire expression in a single vec. So, this shit exists.
 ///
-#[derive(PartialEq, Eq, Hash, Debug)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub enum GeneralOperator {
     // expression/unary
     Plus,
@@ -15,6 +16,9 @@ pub enum GeneralOperator {
     // term
     Multiply,
     Divide,
+    Modulo,
+    // power
+    Power,
     // for later convenience: Comparison
     EqualEqual,
     NotEqual,
@@ -28,23 +32,101 @@ pub enum GeneralOperator {
     Not,
 }
 
+impl GeneralOperator {
+    /// How the operator is written in plank source, for error messages.
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            GeneralOperator::Plus => "+",
+            GeneralOperator::Minus => "-",
+            GeneralOperator::Multiply => "*",
+            GeneralOperator::Divide => "/",
+            GeneralOperator::Modulo => "%",
+            GeneralOperator::Power => "**",
+            GeneralOperator::EqualEqual => "==",
+            GeneralOperator::NotEqual => "!=",
+            GeneralOperator::LessThan => "<",
+            GeneralOperator::LessThanEqualTo => "<=",
+            GeneralOperator::GreaterThan => ">",
+            GeneralOperator::GreaterThanEqualTo => ">=",
+            GeneralOperator::And => "and",
+            GeneralOperator::Or => "or",
+            GeneralOperator::Not => "not",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum DataType {
     Number,
     String,
     Boolean,
+    List(Box<DataType>), // List<Number>, List<List<String>>, etc.
+    Record(String),      // name of the record; might not exist until checked in semantic analysis
+    Enum(String),        // name of the enum
     Void,
     Unknown, // Used when type needs to be resolved before semantic analysis
     Invalid,
 }
 
+impl DataType {
+    /// Parses the type written starting at tokens[idx] (Number, String, Boolean,
+    /// List<type>, or the name of a record or enum).
+    /// Enums are gathered before anything else, so any other name is a record.
+    /// Returns the type and the index of the token right after it, or None if
+    /// the tokens aren't a valid type.
+    pub fn from_tokens(
+        tokens: &[Token],
+        idx: usize,
+        type_table: &TypeTable,
+    ) -> Option<(DataType, usize)> {
+        let token = tokens.get(idx)?;
+        match token.lexeme.as_str() {
+            "Number" => Some((DataType::Number, idx + 1)),
+            "String" => Some((DataType::String, idx + 1)),
+            "Boolean" => Some((DataType::Boolean, idx + 1)),
+            "List" => {
+                if tokens.get(idx + 1)?.token_type != TokenType::LessThan {
+                    return None;
+                }
+                let (element_type, idx) = DataType::from_tokens(tokens, idx + 2, type_table)?;
+                if tokens.get(idx)?.token_type != TokenType::GreaterThan {
+                    return None;
+                }
+                Some((DataType::List(Box::new(element_type)), idx + 1))
+            }
+            _ if token.token_type == TokenType::Identity => {
+                if type_table.contains_enum(&token.lexeme) {
+                    Some((DataType::Enum(token.lexeme.clone()), idx + 1))
+                } else {
+                    Some((DataType::Record(token.lexeme.clone()), idx + 1))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// How the type is written in plank source, for error messages.
+    pub fn to_plank_str(&self) -> String {
+        match self {
+            DataType::List(element_type) => format!("List<{}>", element_type.to_plank_str()),
+            DataType::Record(name) | DataType::Enum(name) => name.clone(),
+            _ => format!("{:?}", self),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValueType {
     FunctionCall,
-    Expression,
+    Expression, // an operand with its own prefix operator (- -x, 2 ** -x); params[0] is a Logical holding just that unary
     InlineNumber,
     InlineString,
     InlineBoolean,
+    ListLiteral,        // [1, 2, 3]
+    RecordConstruction, // Point(1, 2); parsed as a FunctionCall, changed in type resolution
+    Access,             // xs[i], p.x, ps[i].x; raw_text is the name of the variable
+    EnumVariant, // Color.Red; parsed as an Access, changed in type resolution. raw_text is the enum's name
+    InterpolatedString, // "i is {i}"; params are the pieces of text (InlineStrings) and the values put in between
     Variable,
     Invalid,
 }
@@ -54,10 +136,49 @@ pub struct Value {
     pub data_type: DataType,
     pub value_type: ValueType,
     pub raw_text: String, // The raw text from the source, for reference
-    /// Only exists if value_type = FunctionCall; we need to record the expressions
-    /// being passed in as params.
+    /// Only exists if value_type = FunctionCall, RecordConstruction or ListLiteral.
+    /// FunctionCall/RecordConstruction: the expressions being passed in as params.
+    /// ListLiteral: the elements of the list.
     //pub param_values: Option<Vec<Expression>>,
     pub params: Vec<Logical>,
+    /// Only exists if value_type = Access; every step taken from the variable,
+    /// in order (ps[i].x => [[i], .x]).
+    pub accessors: Vec<Accessor>,
+    /// Only exists if value_type = FunctionCall; the c name of the overload
+    /// being called (see FunctionTable::get_c_name), set in type resolution.
+    pub function_c_name: Option<String>,
+    /// Only exists if value_type = FunctionCall or RecordConstruction; the
+    /// name each param was passed with (greet(name: "x")), or None if it was
+    /// passed by position. Emptied once type resolution puts the params in
+    /// order.
+    pub argument_names: Vec<Option<String>>,
+    /// Only for value_type = Variable or Access; whether the variable is a
+    /// global (or constant), set in type resolution.
+    pub is_global: bool,
+}
+
+/// One step of getting at part of a variable: an index into a list, or a
+/// field of a record.
+#[derive(Debug, Clone)]
+pub struct Accessor {
+    pub kind: AccessorKind,
+    /// The type of what this step gets at (ps[i] is a Point, ps[i].x is a Number).
+    pub data_type: DataType,
+}
+
+#[derive(Debug, Clone)]
+pub enum AccessorKind {
+    Index(Logical),
+    Field(String),
+}
+
+impl Accessor {
+    pub fn new(kind: AccessorKind) -> Self {
+        Accessor {
+            kind,
+            data_type: DataType::Unknown,
+        }
+    }
 }
 
 impl Value {
@@ -67,6 +188,10 @@ impl Value {
             value_type,
             raw_text,
             params: Vec::new(),
+            accessors: Vec::new(),
+            function_c_name: None,
+            argument_names: Vec::new(),
+            is_global: false,
         }
     }
 
@@ -81,21 +206,93 @@ impl Value {
             value_type,
             raw_text,
             params: params,
+            accessors: Vec::new(),
+            function_c_name: None,
+            argument_names: Vec::new(),
+            is_global: false,
+        }
+    }
+
+    pub fn new_with_accessors(raw_text: String, accessors: Vec<Accessor>) -> Self {
+        Value {
+            data_type: DataType::Unknown,
+            value_type: ValueType::Access,
+            raw_text,
+            params: Vec::new(),
+            accessors,
+            function_c_name: None,
+            argument_names: Vec::new(),
+            is_global: false,
         }
     }
 
     pub fn invalid() -> Self {
         Value::new(DataType::Invalid, ValueType::Invalid, String::new())
     }
+
+    /// Nests a unary as a single value, so it can be the operand of another
+    /// operator.
+    pub fn from_unary(unary: Unary) -> Self {
+        Value::new_with_params(
+            DataType::Unknown,
+            ValueType::Expression,
+            String::new(),
+            vec![Logical::from_unary(unary)],
+        )
+    }
+
+    /// Calls visit on this value, then on every value nested inside of it
+    /// (params, list elements and indexes).
+    pub fn visit_values<'a>(&'a self, visit: &mut impl FnMut(&'a Value)) {
+        visit(self);
+        for param in &self.params {
+            param.visit_values(visit);
+        }
+        for accessor in &self.accessors {
+            if let AccessorKind::Index(index) = &accessor.kind {
+                index.visit_values(visit);
+            }
+        }
+    }
 }
 
+/// A power with an optional prefix operator (-x, not x, !x).
+/// Prefix operators only apply to the single operand right after them, so
+/// 'not a and b' is '(not a) and b'.
 #[derive(Debug, Clone)]
 pub struct Unary {
-    pub operation: Option<ExpressionOperator>,
-    pub primary: Value,
+    pub operation: Option<UnaryOperator>,
+    pub power: Power,
+    pub data_type: DataType,
+}
+
+/// Prefix operators; Negate only works on Numbers, Not only on Booleans.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnaryOperator {
+    Negate,
+    Not,
+}
+
+/// Exponentiation (**), which binds tighter than everything else, so
+/// -2 ** 2 is -(2 ** 2).
+/// There's only one power operator so we don't keep a list of them; every
+/// value is separated by a **. It's right associative: 2 ** 3 ** 2 is
+/// 2 ** (3 ** 2).
+#[derive(Debug, Clone)]
+pub struct Power {
+    pub values: Vec<Value>,
     pub data_type: DataType,
 }
 
+impl Power {
+    pub fn new() -> Power {
+        Power {
+            values: Vec::new(),
+            data_type: DataType::Unknown,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Term {
     pub unarys: Vec<Unary>,
@@ -117,6 +314,7 @@ impl Term {
 pub enum TermOperator {
     Multiply,
     Divide,
+    Modulo,
     invalidop, // TODO: these should be changed to no op
 }
 
@@ -135,6 +333,24 @@ impl Expression {
             data_type: DataType::Unknown,
         }
     }
+
+    /// Returns the value this expression is made of, if it's nothing but a
+    /// single value (no operators at all).
+    pub fn as_single_value(&self) -> Option<&Value> {
+        let [term] = self.terms.as_slice() else {
+            return None;
+        };
+        let [unary] = term.unarys.as_slice() else {
+            return None;
+        };
+        if unary.operation.is_some() {
+            return None;
+        }
+        match unary.power.values.as_slice() {
+            [value] => Some(value),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -145,6 +361,8 @@ pub enum ExpressionOperator {
 }
 
 /// A comparison expression (handles equality, inequality, etc.)
+/// The parser will happily build a chain (a < b < c), but the analyzer
+/// rejects any comparison with more than one operator.
 #[derive(Debug, Clone)]
 pub struct Comparison {
     pub expressions: Vec<Expression>,
@@ -199,12 +417,120 @@ impl Logical {
     }
 }
 
-/// Operators that can appear at the logical level (&&, ||, !)
+impl Logical {
+    /// A logical that's nothing but the value.
+    pub fn from_value(value: Value) -> Logical {
+        let data_type = value.data_type.clone();
+        Logical::from_unary(Unary {
+            operation: None,
+            power: Power {
+                values: vec![value],
+                data_type: data_type.clone(),
+            },
+            data_type,
+        })
+    }
+
+    /// A logical that's nothing but the unary.
+    pub fn from_unary(unary: Unary) -> Logical {
+        let data_type = unary.data_type.clone();
+        Logical {
+            comparisons: vec![Comparison {
+                expressions: vec![Expression {
+                    terms: vec![Term {
+                        unarys: vec![unary],
+                        operations: Vec::new(),
+                        data_type: data_type.clone(),
+                    }],
+                    operators: Vec::new(),
+                    data_type: data_type.clone(),
+                }],
+                operators: Vec::new(),
+                data_type: data_type.clone(),
+            }],
+            operators: Vec::new(),
+            data_type,
+        }
+    }
+
+    /// Calls visit on every value in this logical, including the ones nested
+    /// inside of other values (see Value::visit_values).
+    pub fn visit_values<'a>(&'a self, visit: &mut impl FnMut(&'a Value)) {
+        let expressions = self.comparisons.iter().flat_map(|comp| &comp.expressions);
+        let unarys = expressions
+            .flat_map(|expr| &expr.terms)
+            .flat_map(|term| &term.unarys);
+        for value in unarys.flat_map(|unary| &unary.power.values) {
+            value.visit_values(visit);
+        }
+    }
+
+    /// Returns the literal this logical is made of, if it's nothing but a
+    /// single literal (Numbers can be negated, ex. -5) or enum variant.
+    /// Used wherever a value has to be known at compile time.
+    pub fn as_literal(&self) -> Option<Literal> {
+        if self.comparisons.len() != 1 {
+            return None;
+        }
+        let comparison = &self.comparisons[0];
+        if comparison.expressions.len() != 1 {
+            return None;
+        }
+        let expression = &comparison.expressions[0];
+        if expression.terms.len() != 1 {
+            return None;
+        }
+        let term = &expression.terms[0];
+        if term.unarys.len() != 1 {
+            return None;
+        }
+        let unary = &term.unarys[0];
+        if unary.power.values.len() != 1 {
+            return None;
+        }
+        let value = &unary.power.values[0];
+
+        match (&value.value_type, &unary.operation) {
+            (ValueType::InlineNumber, None) => value.raw_text.parse().ok().map(Literal::Number),
+            (ValueType::InlineNumber, Some(UnaryOperator::Negate)) => value
+                .raw_text
+                .parse::<i64>()
+                .ok()
+                .map(|number| Literal::Number(-number)),
+            (ValueType::InlineString, None) => Some(Literal::String(value.raw_text.clone())),
+            (ValueType::InlineBoolean, None) => Some(Literal::Boolean(value.raw_text == "true")),
+            (ValueType::EnumVariant, None) => match value.accessors.as_slice() {
+                [
+                    Accessor {
+                        kind: AccessorKind::Field(variant),
+                        ..
+                    },
+                ] => Some(Literal::EnumVariant {
+                    enum_name: value.raw_text.clone(),
+                    variant: variant.clone(),
+                }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A value that's known at compile time.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Number(i64),
+    String(String),
+    Boolean(bool),
+    EnumVariant { enum_name: String, variant: String },
+}
+
+/// Operators that can appear at the logical level (&&, ||)
+/// (not/! is a UnaryOperator, since it only takes one operand)
 #[derive(Debug, Clone, PartialEq)]
 pub enum LogicalOperator {
     And,
     Or,
-    Not,
     invalidop,
 }
 
@@ -219,10 +545,20 @@ pub fn convert_token_type_to_expression_op(token_type: TokenType) -> ExpressionO
     }
 }
 
+/// Unary plus doesn't do anything, so it doesn't get an operator.
+pub fn convert_token_type_to_unary_op(token_type: TokenType) -> Option<UnaryOperator> {
+    match token_type {
+        TokenType::Minus => Some(UnaryOperator::Negate),
+        TokenType::Bang => Some(UnaryOperator::Not),
+        _ => None,
+    }
+}
+
 pub fn convert_token_type_to_term_op(token_type: TokenType) -> TermOperator {
     match token_type {
         TokenType::Asterisk => TermOperator::Multiply,
         TokenType::Slash => TermOperator::Divide,
+        TokenType::Percent => TermOperator::Modulo,
         _ => TermOperator::invalidop,
     }
 }
@@ -243,7 +579,6 @@ pub fn convert_token_type_to_logical_op(token_type: TokenType) -> LogicalOperato
     match token_type {
         TokenType::DoubleAmpersand => LogicalOperator::And,
         TokenType::DoubleBar => LogicalOperator::Or,
-        TokenType::Bang => LogicalOperator::Not,
         _ => LogicalOperator::invalidop,
     }
 }
@@ -258,10 +593,18 @@ pub fn convert_expression_op_to_general(op: ExpressionOperator) -> Option<Genera
     }
 }
 
+pub fn convert_unary_op_to_general(op: UnaryOperator) -> GeneralOperator {
+    match op {
+        UnaryOperator::Negate => GeneralOperator::Minus,
+        UnaryOperator::Not => GeneralOperator::Not,
+    }
+}
+
 pub fn convert_term_op_to_general(op: TermOperator) -> Option<GeneralOperator> {
     match op {
         TermOperator::Multiply => Some(GeneralOperator::Multiply),
         TermOperator::Divide => Some(GeneralOperator::Divide),
+        TermOperator::Modulo => Some(GeneralOperator::Modulo),
         TermOperator::invalidop => None,
     }
 }
@@ -282,7 +625,6 @@ pub fn convert_logical_op_to_general(op: LogicalOperator) -> Option<GeneralOpera
     match op {
         LogicalOperator::And => Some(GeneralOperator::And),
         LogicalOperator::Or => Some(GeneralOperator::Or),
-        LogicalOperator::Not => Some(GeneralOperator::Not),
         LogicalOperator::invalidop => None,
     }
 }
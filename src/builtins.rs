@@ -0,0 +1,156 @@
+use crate::ast::DataType;
+
+/**
+ * Functions that come with plank. They aren't in the FunctionTable (they have
+ * no plank declaration); type resolution looks them up here instead, and user
+ * functions can't use their names.
+ * Each one is written in c as a runtime helper (or an inline expression), and
+ * a helper is only put in the c file when the program calls its builtin.
+ */
+pub struct BuiltinFunction {
+    pub name: &'static str,
+    pub parameters: &'static [BuiltinParameter],
+    pub return_type: DataType,
+}
+
+pub struct BuiltinParameter {
+    pub name: &'static str,
+    pub parameter_type: BuiltinParameterType,
+}
+
+/// What can be passed to a builtin's parameter. Some builtins take more than
+/// one type (length works on Strings and Lists).
+pub enum BuiltinParameterType {
+    Number,
+    String,
+    StringOrList,
+    /// Anything that can be turned into a String: a Number, String, Boolean or
+    /// an enum variant.
+    Convertible,
+}
+
+impl BuiltinParameterType {
+    pub fn accepts(&self, data_type: &DataType) -> bool {
+        match self {
+            BuiltinParameterType::Number => *data_type == DataType::Number,
+            BuiltinParameterType::String => *data_type == DataType::String,
+            BuiltinParameterType::StringOrList => {
+                matches!(data_type, DataType::String | DataType::List(_))
+            }
+            BuiltinParameterType::Convertible => matches!(
+                data_type,
+                DataType::Number | DataType::String | DataType::Boolean | DataType::Enum(_)
+            ),
+        }
+    }
+
+    /// For error messages (ex. "toNumber expects a String").
+    pub fn to_plank_str(&self) -> &'static str {
+        match self {
+            BuiltinParameterType::Number => "a Number",
+            BuiltinParameterType::String => "a String",
+            BuiltinParameterType::StringOrList => "a String or a List",
+            BuiltinParameterType::Convertible => "a Number, String, Boolean or enum",
+        }
+    }
+}
+
+const fn parameter(name: &'static str, parameter_type: BuiltinParameterType) -> BuiltinParameter {
+    BuiltinParameter {
+        name,
+        parameter_type,
+    }
+}
+
+pub static BUILTIN_FUNCTIONS: [BuiltinFunction; 13] = [
+    BuiltinFunction {
+        name: "abs",
+        parameters: &[parameter("number", BuiltinParameterType::Number)],
+        return_type: DataType::Number,
+    },
+    BuiltinFunction {
+        name: "min",
+        parameters: &[
+            parameter("a", BuiltinParameterType::Number),
+            parameter("b", BuiltinParameterType::Number),
+        ],
+        return_type: DataType::Number,
+    },
+    BuiltinFunction {
+        name: "max",
+        parameters: &[
+            parameter("a", BuiltinParameterType::Number),
+            parameter("b", BuiltinParameterType::Number),
+        ],
+        return_type: DataType::Number,
+    },
+    BuiltinFunction {
+        name: "length",
+        parameters: &[parameter("items", BuiltinParameterType::StringOrList)],
+        return_type: DataType::Number,
+    },
+    // The characters from start up to (not including) end.
+    BuiltinFunction {
+        name: "substring",
+        parameters: &[
+            parameter("text", BuiltinParameterType::String),
+            parameter("start", BuiltinParameterType::Number),
+            parameter("end", BuiltinParameterType::Number),
+        ],
+        return_type: DataType::String,
+    },
+    BuiltinFunction {
+        name: "contains",
+        parameters: &[
+            parameter("text", BuiltinParameterType::String),
+            parameter("part", BuiltinParameterType::String),
+        ],
+        return_type: DataType::Boolean,
+    },
+    BuiltinFunction {
+        name: "upper",
+        parameters: &[parameter("text", BuiltinParameterType::String)],
+        return_type: DataType::String,
+    },
+    BuiltinFunction {
+        name: "lower",
+        parameters: &[parameter("text", BuiltinParameterType::String)],
+        return_type: DataType::String,
+    },
+    BuiltinFunction {
+        name: "trim",
+        parameters: &[parameter("text", BuiltinParameterType::String)],
+        return_type: DataType::String,
+    },
+    BuiltinFunction {
+        name: "toString",
+        parameters: &[parameter("value", BuiltinParameterType::Convertible)],
+        return_type: DataType::String,
+    },
+    BuiltinFunction {
+        name: "toNumber",
+        parameters: &[parameter("text", BuiltinParameterType::String)],
+        return_type: DataType::Number,
+    },
+    BuiltinFunction {
+        name: "toBoolean",
+        parameters: &[parameter("text", BuiltinParameterType::String)],
+        return_type: DataType::Boolean,
+    },
+    // Stops the program right away, with code as its exit status (0 to 255).
+    BuiltinFunction {
+        name: "exit",
+        parameters: &[parameter("code", BuiltinParameterType::Number)],
+        return_type: DataType::Void,
+    },
+];
+
+pub fn get_builtin(name: &str) -> Option<&'static BuiltinFunction> {
+    BUILTIN_FUNCTIONS
+        .iter()
+        .find(|builtin| builtin.name == name)
+}
+
+pub fn is_builtin_function(name: &str) -> bool {
+    get_builtin(name).is_some()
+}
@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use crate::ast::{FunctionTable, Statement, ValueType, visit_statements};
+
+/**
+ * Which functions call which, built from the AST after type resolution (so
+ * record constructions aren't mistaken for calls, and every call knows which
+ * overload it goes to).
+ * Functions are named by their c name (see FunctionTable::get_c_name), since
+ * overloads share their plank name.
+ * The top level statements are the entry point; a function they can't reach
+ * is never called.
+ */
+#[derive(Debug)]
+pub struct CallGraph {
+    /// Every function declared at the top level, in the order they're declared.
+    functions: Vec<CallGraphNode>,
+    /// Functions called directly by the top level statements.
+    entry_calls: Vec<String>,
+}
+
+#[derive(Debug)]
+struct CallGraphNode {
+    c_name: String,
+    name: String,
+    line_declared_on: u32,
+    /// Functions called in the body, in order (with duplicates).
+    calls: Vec<String>,
+}
+
+impl CallGraph {
+    pub fn new(statements: &[Statement], function_table: &FunctionTable) -> Self {
+        // Builtins (and calls that didn't resolve to a function) aren't part
+        // of the graph.
+        let get_calls = |body: &[Statement]| {
+            let mut calls = Vec::new();
+            visit_statements(body, &mut |statement| {
+                statement.visit_values(&mut |value| {
+                    if value.value_type == ValueType::FunctionCall
+                        && let Some(c_name) = &value.function_c_name
+                    {
+                        calls.push(c_name.clone());
+                    }
+                });
+            });
+            calls
+        };
+
+        let functions = statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::FunctionDeclaration(func_decl) => {
+                    let function_def = function_table
+                        .get_func_def(&func_decl.function_name, &func_decl.parameter_types());
+                    // A redeclaration is already an error, and shares its c
+                    // name with the declaration that's kept.
+                    if function_def
+                        .is_some_and(|def| def.line_declared_on != func_decl.line_declared_on)
+                    {
+                        return None;
+                    }
+                    let c_name = function_def
+                        .map(|def| function_table.get_c_name(def))
+                        .unwrap_or_else(|| func_decl.function_name.clone());
+                    // Overloads are told apart by their parameter types in warnings.
+                    let is_overloaded =
+                        function_table.get_overloads(&func_decl.function_name).len() > 1;
+                    let name = match function_def {
+                        Some(def) if is_overloaded => def.to_plank_str(),
+                        _ => func_decl.function_name.clone(),
+                    };
+                    Some(CallGraphNode {
+                        c_name,
+                        name,
+                        line_declared_on: func_decl.line_declared_on,
+                        calls: get_calls(&func_decl.body),
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+
+        // Function bodies aren't visited, only the statements that run as
+        // the file runs.
+        let entry_calls = get_calls(statements);
+
+        CallGraph {
+            functions,
+            entry_calls,
+        }
+    }
+
+    /// The function, and every function it could end up calling.
+    pub fn get_reachable_functions<'a>(&'a self, c_name: &'a str) -> Vec<&'a str> {
+        self.get_reachable_from([c_name])
+    }
+
+    /// Whether the function can end up calling itself, directly or through
+    /// other functions (mutual recursion).
+    pub fn is_recursive(&self, c_name: &str) -> bool {
+        self.get_calls(c_name)
+            .iter()
+            .any(|called| self.get_reachable_functions(called).contains(&c_name))
+    }
+
+    pub fn get_recursive_functions(&self) -> Vec<&str> {
+        self.functions
+            .iter()
+            .map(|node| node.c_name.as_str())
+            .filter(|c_name| self.is_recursive(c_name))
+            .collect()
+    }
+
+    /// Functions that can't be reached from the top level statements (only
+    /// calling themselves, or being called by other uncalled functions,
+    /// doesn't count), along with the line they're declared on.
+    pub fn get_uncalled_functions(&self) -> Vec<(&str, u32)> {
+        let reachable = self.get_reachable_from(self.entry_calls.iter().map(String::as_str));
+
+        self.functions
+            .iter()
+            .filter(|node| !reachable.contains(&node.c_name.as_str()))
+            .map(|node| (node.name.as_str(), node.line_declared_on))
+            .collect()
+    }
+
+    fn get_calls(&self, c_name: &str) -> &[String] {
+        self.functions
+            .iter()
+            .find(|node| node.c_name == c_name)
+            .map(|node| node.calls.as_slice())
+            .unwrap_or_default()
+    }
+
+    fn get_reachable_from<'a>(&'a self, start: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+        let mut reachable: Vec<&str> = Vec::new();
+        let mut seen = HashSet::new();
+        for name in start {
+            if seen.insert(name) {
+                reachable.push(name);
+            }
+        }
+
+        let mut idx = 0;
+        while idx < reachable.len() {
+            for called in self.get_calls(reachable[idx]) {
+                if seen.insert(called) {
+                    reachable.push(called);
+                }
+            }
+            idx += 1;
+        }
+
+        reachable
+    }
+}
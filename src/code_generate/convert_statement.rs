@@ -1,10 +1,15 @@
-use crate::ast::{Comparison, Expression, RawFunctionCallStatement, Term, Unary};
-use crate::ast::{ComparisonOperator, ExpressionOperator, LogicalOperator, TermOperator};
+use crate::ast::{Accessor, AccessorKind, EnumSymbol, RecordSymbol, TypeTable};
 use crate::ast::{
-    DataType, IfStatement, PrintStatement, ReturnStatement, Value, VariableAssignmentStatement,
-    VariableDeclarationStatement, WhileStatement,
+    BreakStatement, DataType, ForStatement, IfStatement, Literal, MatchStatement, PrintStatement,
+    ReturnStatement, Value, VariableAssignmentStatement, VariableDeclarationStatement,
+    WhileStatement,
+};
+use crate::ast::{Comparison, Expression, Logical, Power, RawFunctionCallStatement, Term, Unary};
+use crate::ast::{
+    ComparisonOperator, ExpressionOperator, LogicalOperator, TermOperator, UnaryOperator,
 };
 use crate::ast::{FunctionDeclarationStatement, FunctionSymbol, Statement, ValueType};
+use crate::builtins::is_builtin_function;
 use std::fmt;
 
 // Implement Display for DataType so we can call .to_string() on it
@@ -12,10 +17,13 @@ use std::fmt;
 impl fmt::Display for DataType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            DataType::Number => write!(f, "int"),
+            DataType::Number => write!(f, "int64_t"),
             DataType::String => write!(f, "char*"),
             DataType::Void => write!(f, "void"),
             DataType::Boolean => write!(f, "bool"),
+            DataType::List(_) => write!(f, "PlankList*"),
+            DataType::Record(name) => write!(f, "struct {}", name),
+            DataType::Enum(name) => write!(f, "enum {}", name),
             DataType::Unknown => write!(f, "auto"),
             DataType::Invalid => write!(f, "/* invalid type */"),
         }
@@ -56,40 +64,108 @@ pub fn to_code_str(statement: &Statement) -> String {
         Statement::Print(print_statement) => to_code_str_print(print_statement),
         Statement::If(if_statement) => to_code_str_if(if_statement),
         Statement::While(while_statement) => to_code_str_while(while_statement),
+        Statement::For(for_statement) => to_code_str_for(for_statement),
+        Statement::Break(break_stmt) => to_code_str_break(break_stmt),
+        Statement::Continue(_) => "continue;\n".to_string(),
+        Statement::Match(match_stmt) => to_code_str_match(match_stmt),
         Statement::RawFunctionCall(stmt) => to_code_str_raw_function_call(stmt),
+        // Records and enums are all defined at the top of the file, see to_code_str_user_types.
+        Statement::RecordDeclaration(_) | Statement::EnumDeclaration(_) => String::new(),
     }
 }
 
+/// Constants are at the top of the file (see generate_code_str), so every
+/// function can use them.
 fn to_code_str_var_decl(var_decl: &VariableDeclarationStatement) -> String {
+    let qualifier = if var_decl.is_const {
+        "static const "
+    } else {
+        ""
+    };
+
+    // A negative number would go through plank_neg, which c doesn't allow at
+    // file scope. Constants are always literals, so just write the number.
+    let assigned_str = match var_decl.assigned_logical.as_literal() {
+        Some(Literal::Number(number)) if var_decl.is_const => number.to_string(),
+        // TODO: null terminate the string here somehow
+        _ => to_code_str_logical(&var_decl.assigned_logical, var_decl.line_declared_on),
+    };
+
+    let name_str = if var_decl.is_const {
+        to_code_str_global_name(&var_decl.symbol_name)
+    } else {
+        var_decl.symbol_name.clone()
+    };
+
     format!(
-        "{} {} = {};\n",
+        "{}{} {} = {};\n",
+        qualifier,
         var_decl.data_type.to_string(),
-        var_decl.symbol_name,
-        //to_code_str_value(&var_decl.assigned_value)
-        // TODO: null terminate the string here somehow
-        to_code_str_logical(&var_decl.assigned_logical)
+        name_str,
+        assigned_str
+    )
+}
+
+/// Globals are declared at file scope (see generate_code_str), and set in
+/// main when their declaration is reached.
+pub fn to_code_str_global_decl(var_decl: &VariableDeclarationStatement) -> String {
+    format!(
+        "{} {};\n",
+        var_decl.data_type,
+        to_code_str_global_name(&var_decl.symbol_name)
+    )
+}
+
+pub fn to_code_str_global_init(var_decl: &VariableDeclarationStatement) -> String {
+    format!(
+        "{} = {};\n",
+        to_code_str_global_name(&var_decl.symbol_name),
+        to_code_str_logical(&var_decl.assigned_logical, var_decl.line_declared_on)
     )
 }
 
+/// count => plank_global_count
+/// Globals (and constants) are at file scope, so they get a prefix to keep them
+/// from clashing with anything in the c standard library (ex. index, exit).
+fn to_code_str_global_name(name: &str) -> String {
+    format!("plank_global_{}", name)
+}
+
+/// The c name of a variable being used.
+fn to_code_str_var_name(name: &str, is_global: bool) -> String {
+    if is_global {
+        to_code_str_global_name(name)
+    } else {
+        name.to_string()
+    }
+}
+
 fn to_code_str_var_assignment(var_assign: &VariableAssignmentStatement) -> String {
+    let target_str = to_code_str_access(
+        &to_code_str_var_name(&var_assign.var_name, var_assign.is_global),
+        &var_assign.accessors,
+        var_assign.line_number,
+    );
+
     format!(
         "{} = {};\n",
-        var_assign.var_name,
-        to_code_str_logical(&var_assign.assigned_logical)
+        target_str,
+        to_code_str_logical(&var_assign.assigned_logical, var_assign.line_number)
     )
 }
 
-fn to_code_str_value(value: &Value) -> String {
+fn to_code_str_value(value: &Value, line: u32) -> String {
     let mut code_str = String::new();
     match value.value_type {
         ValueType::FunctionCall => {
             // Use the dedicated function call converter
-            to_code_str_function_call(value)
+            to_code_str_function_call(value, line)
         }
-        ValueType::InlineNumber | ValueType::Variable => {
+        ValueType::InlineNumber => {
             code_str.push_str(&value.raw_text);
             code_str
         }
+        ValueType::Variable => to_code_str_var_name(&value.raw_text, value.is_global),
         ValueType::InlineString => {
             code_str.push_str("\"");
             code_str.push_str(&value.raw_text);
@@ -100,18 +176,279 @@ fn to_code_str_value(value: &Value) -> String {
             code_str.push_str(&value.raw_text);
             code_str
         }
+        ValueType::ListLiteral => to_code_str_list_literal(value, line),
+        ValueType::Expression => match value.params.first() {
+            Some(nested) => format!("({})", to_code_str_logical(nested, line)),
+            None => code_str,
+        },
+        ValueType::Access => to_code_str_access(
+            &to_code_str_var_name(&value.raw_text, value.is_global),
+            &value.accessors,
+            line,
+        ),
+        ValueType::RecordConstruction => to_code_str_record_construction(value, line),
+        ValueType::EnumVariant => match value.accessors.first().map(|accessor| &accessor.kind) {
+            Some(AccessorKind::Field(variant)) => {
+                to_code_str_enum_variant(&value.raw_text, variant)
+            }
+            _ => code_str,
+        },
+        ValueType::InterpolatedString => {
+            let (format_str, arguments) = to_code_str_format(value, line);
+            let mut code_str = format!("plank_format(\"{}\"", format_str);
+            for argument in arguments {
+                code_str.push_str(", ");
+                code_str.push_str(&argument);
+            }
+            code_str.push(')');
+            code_str
+        }
         _ => code_str,
     }
 }
 
-fn to_code_str_function_call(value: &Value) -> String {
+/// The printf format string (without its quotes) and arguments that write out
+/// an interpolated string. The pieces of text go straight into the format
+/// string; every value gets the specifier for its type.
+fn to_code_str_format(value: &Value, line: u32) -> (String, Vec<String>) {
+    let mut format_str = String::new();
+    let mut arguments = Vec::new();
+
+    for piece in &value.params {
+        if let Some(Literal::String(text)) = piece.as_literal() {
+            format_str.push_str(&text.replace('%', "%%"));
+            continue;
+        }
+
+        let piece_str = to_code_str_logical(piece, line);
+        match &piece.data_type {
+            DataType::Number => {
+                format_str.push_str("%\" PRId64 \"");
+                arguments.push(format!("(int64_t)({})", piece_str));
+            }
+            DataType::Boolean => {
+                format_str.push_str("%s");
+                arguments.push(format!("(({}) ? \"true\" : \"false\")", piece_str));
+            }
+            DataType::Enum(name) => {
+                format_str.push_str("%s");
+                arguments.push(format!("plank_{}_names[{}]", name, piece_str));
+            }
+            _ => {
+                format_str.push_str("%s");
+                arguments.push(piece_str);
+            }
+        }
+    }
+
+    (format_str, arguments)
+}
+
+/// The elements are put in a c array literal that's copied into the new list.
+/// [1, 2] => plank_list_new(2, sizeof(int64_t), (int64_t[]){1, 2})
+fn to_code_str_list_literal(value: &Value, line: u32) -> String {
+    let element_type = match &value.data_type {
+        DataType::List(element_type) => element_type,
+        _ => &DataType::Invalid,
+    };
+
+    let elements_str = value
+        .params
+        .iter()
+        .map(|element| to_code_str_logical(element, line))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    format!(
+        "plank_list_new({}, sizeof({}), ({}[]){{{}}})",
+        value.params.len(),
+        element_type,
+        element_type,
+        elements_str
+    )
+}
+
+/// ps[i].x => the x field of the element at i of ps.
+/// plank_list_at checks the bounds and gives back a pointer to the element,
+/// so this can be read from or assigned to. Records are c structs, so fields
+/// are just a '.'
+fn to_code_str_access(var_name: &str, accessors: &[Accessor], line: u32) -> String {
+    let mut code_str = var_name.to_string();
+
+    for accessor in accessors {
+        code_str = match &accessor.kind {
+            AccessorKind::Index(index) => format!(
+                "(*({}*)plank_list_at({}, {}, {}))",
+                accessor.data_type,
+                code_str,
+                to_code_str_logical(index, line),
+                line
+            ),
+            AccessorKind::Field(field_name) => format!("{}.{}", code_str, field_name),
+        };
+    }
+
+    code_str
+}
+
+/// Point(1, 2) => (struct Point){1, 2}
+/// The fields are given in the order they're declared, same as the struct.
+fn to_code_str_record_construction(value: &Value, line: u32) -> String {
+    let fields_str = value
+        .params
+        .iter()
+        .map(|field| to_code_str_logical(field, line))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    format!("({}){{{}}}", value.data_type, fields_str)
+}
+
+/// Color.Red => plank_Color_Red
+/// Variants get the enum's name in front, since c enum variants are all global.
+fn to_code_str_enum_variant(enum_name: &str, variant: &str) -> String {
+    format!("plank_{}_{}", enum_name, variant)
+}
+
+/// Every enum becomes a c enum, along with an array of its variant names
+/// for printing.
+/// Every record becomes a c struct, along with a function that prints it.
+/// A struct has to be defined before any struct that holds it, so records are
+/// defined after the records in their fields (records in Lists are behind a
+/// pointer, so they don't matter).
+pub fn to_code_str_user_types(type_table: &TypeTable) -> String {
     let mut code_str = String::new();
 
-    code_str.push_str(&value.raw_text);
+    for enum_symbol in type_table.get_all_enums() {
+        code_str.push_str(&to_code_str_enum(enum_symbol));
+    }
+
+    let mut defined_names: Vec<&str> = Vec::new();
+    for record in type_table.get_all_records() {
+        code_str.push_str(&to_code_str_record_struct(
+            record,
+            type_table,
+            &mut defined_names,
+        ));
+    }
+
+    // The print functions can call each other, so declare them all first.
+    for record in type_table.get_all_records() {
+        code_str.push_str(&to_code_str_record_print_header(record));
+        code_str.push_str(";\n");
+    }
+    for record in type_table.get_all_records() {
+        code_str.push_str(&to_code_str_record_print_function(record));
+    }
+
+    code_str.push('\n');
+    code_str
+}
+
+fn to_code_str_enum(enum_symbol: &EnumSymbol) -> String {
+    let name = &enum_symbol.identifier;
+    let variants_str = enum_symbol
+        .variants
+        .iter()
+        .map(|variant| to_code_str_enum_variant(name, variant))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let variant_names_str = enum_symbol
+        .variants
+        .iter()
+        .map(|variant| format!("\"{}\"", variant))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    format!(
+        "enum {name} {{ {variants_str} }};\n\
+         static const char* plank_{name}_names[] = {{ {variant_names_str} }};\n"
+    )
+}
+
+fn to_code_str_record_struct<'a>(
+    record: &'a RecordSymbol,
+    type_table: &'a TypeTable,
+    defined_names: &mut Vec<&'a str>,
+) -> String {
+    if defined_names.contains(&record.identifier.as_str()) {
+        return String::new();
+    }
+    // The analyzer makes sure records don't contain themselves, so this ends.
+    defined_names.push(&record.identifier);
+
+    let mut code_str = String::new();
+    for field in &record.fields {
+        if let DataType::Record(field_record_name) = &field.data_type
+            && let Some(field_record) = type_table.get_record(field_record_name)
+        {
+            code_str.push_str(&to_code_str_record_struct(
+                field_record,
+                type_table,
+                defined_names,
+            ));
+        }
+    }
+
+    code_str.push_str(&format!("struct {} {{\n", record.identifier));
+    for field in &record.fields {
+        code_str.push_str(&format!("   {} {};\n", field.data_type, field.name));
+    }
+    code_str.push_str("};\n");
+    code_str
+}
+
+fn to_code_str_record_print_header(record: &RecordSymbol) -> String {
+    format!(
+        "void plank_print_{}(FILE* stream, struct {} value)",
+        record.identifier, record.identifier
+    )
+}
+
+/// Records print like Point(x: 1, y: 2), to stdout or stderr.
+fn to_code_str_record_print_function(record: &RecordSymbol) -> String {
+    let mut code_str = to_code_str_record_print_header(record);
+    code_str.push_str(" {\n");
+    code_str.push_str(&format!(
+        "   fprintf(stream, \"{}(\");\n",
+        record.identifier
+    ));
+
+    for (idx, field) in record.fields.iter().enumerate() {
+        let separator = if idx == 0 { "" } else { ", " };
+        code_str.push_str(&format!(
+            "   fprintf(stream, \"{}{}: \");\n",
+            separator, field.name
+        ));
+        code_str.push_str("   ");
+        code_str.push_str(&to_code_str_printf(
+            &format!("value.{}", field.name),
+            &field.data_type,
+            "stream",
+            0,
+        ));
+        code_str.push('\n');
+    }
+
+    code_str.push_str("   fprintf(stream, \")\");\n");
+    code_str.push_str("}\n");
+    code_str
+}
+
+fn to_code_str_function_call(value: &Value, line: u32) -> String {
+    let mut code_str = String::new();
+
+    if is_builtin_function(&value.raw_text) {
+        return to_code_str_builtin_call(value, line);
+    }
+
+    // Set to the overload being called in type resolution.
+    let c_name = value.function_c_name.as_ref().unwrap_or(&value.raw_text);
+    code_str.push_str(c_name);
     code_str.push_str("(");
 
     for (idx, param) in value.params.iter().enumerate() {
-        code_str.push_str(&to_code_str_logical(param));
+        code_str.push_str(&to_code_str_logical(param, line));
         if idx < value.params.len() - 1 {
             code_str.push_str(", ");
         }
@@ -121,27 +458,65 @@ fn to_code_str_function_call(value: &Value) -> String {
     code_str
 }
 
+/// Most builtins call their runtime helper (plank_<name>); the rest are short
+/// enough to write inline. The helpers that can stop the program with a
+/// runtime error are also passed the line.
+fn to_code_str_builtin_call(value: &Value, line: u32) -> String {
+    let arguments: Vec<String> = value
+        .params
+        .iter()
+        .map(|param| to_code_str_logical(param, line))
+        .collect();
+    let argument_type = value.params.first().map(|param| &param.data_type);
+
+    match (value.raw_text.as_str(), argument_type) {
+        ("length", Some(DataType::String)) => format!("((int64_t)strlen({}))", arguments[0]),
+        ("length", _) => format!("({})->length", arguments[0]),
+        ("toString", Some(DataType::Number)) => format!("plank_to_string({})", arguments[0]),
+        ("toString", Some(DataType::Boolean)) => {
+            format!("(({}) ? \"true\" : \"false\")", arguments[0])
+        }
+        ("toString", Some(DataType::Enum(name))) => {
+            format!("((char*)plank_{}_names[{}])", name, arguments[0])
+        }
+        ("toString", _) => arguments[0].clone(),
+        ("abs", _) | ("substring", _) | ("exit", _) => {
+            format!(
+                "plank_{}({}, {})",
+                value.raw_text,
+                arguments.join(", "),
+                line
+            )
+        }
+        ("toNumber", _) => format!("plank_to_number({}, {})", arguments[0], line),
+        ("toBoolean", _) => format!("plank_to_boolean({}, {})", arguments[0], line),
+        _ => format!("plank_{}({})", value.raw_text, arguments.join(", ")),
+    }
+}
+
 fn to_code_str_return(return_stmt: &ReturnStatement) -> String {
     format!(
         "return {};\n",
-        to_code_str_logical(&return_stmt.return_value)
+        to_code_str_logical(&return_stmt.return_value, return_stmt.line_declared_on)
     )
 }
 
 pub fn to_code_str_func_decl_stmt(
     func_stmt: &FunctionDeclarationStatement,
     function_def: &FunctionSymbol,
+    c_name: &str,
 ) -> String {
     let mut code = String::new();
 
-    code.push_str(&convert_function_header_to_code_str(function_def));
+    code.push_str(&convert_function_header_to_code_str(function_def, c_name));
 
     code.push_str("{\n");
 
     for statement in &func_stmt.body {
         code.push_str("   ");
 
-        // WARNING: this will break I think if a function is declared in a function.
+        // Functions declared in here are rejected by the analyzer, so the
+        // body never has one.
         code.push_str(&to_code_str(statement));
     }
 
@@ -149,12 +524,59 @@ pub fn to_code_str_func_decl_stmt(
     code
 }
 
-pub fn convert_function_header_to_code_str(function_def: &FunctionSymbol) -> String {
+/// With a recursion limit, a recursive function's body is moved into
+/// plank_recursive_<c_name>, and <c_name> becomes a wrapper that keeps track
+/// of how deep the recursion is around the call to it.
+pub fn to_code_str_depth_limited_func_decl_stmt(
+    func_stmt: &FunctionDeclarationStatement,
+    function_def: &FunctionSymbol,
+    c_name: &str,
+) -> String {
+    let body_c_name = format!("plank_recursive_{}", c_name);
+
+    let mut code = String::from("static ");
+    code.push_str(&to_code_str_func_decl_stmt(
+        func_stmt,
+        function_def,
+        &body_c_name,
+    ));
+
+    let args: Vec<&str> = function_def
+        .parameters
+        .iter()
+        .map(|param| param.name.as_str())
+        .collect();
+    let call_str = format!("{}({})", body_c_name, args.join(", "));
+
+    code.push_str(&convert_function_header_to_code_str(function_def, c_name));
+    code.push_str("{\n");
+    code.push_str(&format!(
+        "   plank_enter_recursion(\"{}\", {});\n",
+        function_def.identifier, function_def.line_declared_on
+    ));
+    if function_def.return_type == DataType::Void {
+        code.push_str(&format!("   {};\n", call_str));
+        code.push_str("   plank_recursion_depth--;\n");
+    } else {
+        code.push_str(&format!(
+            "   {} plank_result = {};\n",
+            function_def.return_type, call_str
+        ));
+        code.push_str("   plank_recursion_depth--;\n");
+        code.push_str("   return plank_result;\n");
+    }
+    code.push_str("}\n");
+    code
+}
+
+/// c_name is the name from FunctionTable::get_c_name, since overloads can't
+/// share a name in c.
+pub fn convert_function_header_to_code_str(function_def: &FunctionSymbol, c_name: &str) -> String {
     let mut code_str = String::new();
     code_str.push_str(&format!(
         "{} {}(",
         function_def.return_type.to_string(),
-        function_def.identifier
+        c_name
     ));
 
     for (i, param) in function_def.parameters.iter().enumerate() {
@@ -168,59 +590,86 @@ pub fn convert_function_header_to_code_str(function_def: &FunctionSymbol) -> Str
     code_str
 }
 
-pub fn to_code_str_expr(expr: &Expression) -> String {
+/// Arithmetic goes through the runtime helpers (plank_add, etc.) so overflow is
+/// caught. Operators are left associative, so each helper call wraps everything
+/// generated to the left of it: a - b + c => plank_add(plank_sub(a, b), c)
+pub fn to_code_str_expr(expr: &Expression, line: u32) -> String {
     let mut code_str = String::new();
 
     if !expr.terms.is_empty() {
-        code_str.push_str(&to_code_str_term(&expr.terms[0]));
+        code_str.push_str(&to_code_str_term(&expr.terms[0], line));
 
         for (idx, term) in expr.terms.iter().skip(1).enumerate() {
             if idx < expr.operators.len() {
-                code_str.push_str(&format!(
-                    " {} ",
-                    expression_operator_to_str(&expr.operators[idx])
-                ));
+                code_str = format!(
+                    "{}({}, {}, {})",
+                    expression_operator_to_runtime_fn(&expr.operators[idx]),
+                    code_str,
+                    to_code_str_term(term, line),
+                    line
+                );
             }
-            code_str.push_str(&to_code_str_term(term));
         }
     }
 
     code_str
 }
 
-fn to_code_str_term(term: &Term) -> String {
+fn to_code_str_term(term: &Term, line: u32) -> String {
     let mut code_str = String::new();
 
     if !term.unarys.is_empty() {
-        code_str.push_str(&to_code_str_unary(&term.unarys[0]));
+        code_str.push_str(&to_code_str_unary(&term.unarys[0], line));
 
         for (idx, unary) in term.unarys.iter().skip(1).enumerate() {
             if idx < term.operations.len() {
-                code_str.push_str(&format!(
-                    " {} ",
-                    term_operator_to_str(&term.operations[idx])
-                ));
+                code_str = format!(
+                    "{}({}, {}, {})",
+                    term_operator_to_runtime_fn(&term.operations[idx]),
+                    code_str,
+                    to_code_str_unary(unary, line),
+                    line
+                );
             }
-            code_str.push_str(&to_code_str_unary(unary));
         }
     }
 
     code_str
 }
 
-fn to_code_str_unary(unary: &Unary) -> String {
-    let mut code_str = String::new();
+fn to_code_str_unary(unary: &Unary, line: u32) -> String {
+    let power_str = to_code_str_power(&unary.power, line);
 
-    if let Some(ref operation) = unary.operation {
-        code_str.push_str(&expression_operator_to_str(operation));
+    match unary.operation {
+        // Negating the smallest Number overflows, so it needs a check as well.
+        Some(UnaryOperator::Negate) => format!("plank_neg({}, {})", power_str, line),
+        Some(UnaryOperator::Not) => format!("!({})", power_str),
+        None => power_str,
     }
+}
+
+/// ** is right associative, so build the helper calls from the right:
+/// a ** b ** c => plank_pow(a, plank_pow(b, c))
+fn to_code_str_power(power: &Power, line: u32) -> String {
+    let mut code_str = String::new();
 
-    code_str.push_str(&to_code_str_value(&unary.primary));
+    if let Some((last, rest)) = power.values.split_last() {
+        code_str.push_str(&to_code_str_value(last, line));
+
+        for value in rest.iter().rev() {
+            code_str = format!(
+                "plank_pow({}, {}, {})",
+                to_code_str_value(value, line),
+                code_str,
+                line
+            );
+        }
+    }
 
     code_str
 }
 
-pub fn to_code_str_comparison(comparison: &Comparison) -> String {
+pub fn to_code_str_comparison(comparison: &Comparison, line: u32) -> String {
     let mut code_str = String::new();
     // How will this work?
     // check if the common expression type of the comparison is a String.
@@ -234,10 +683,10 @@ pub fn to_code_str_comparison(comparison: &Comparison) -> String {
         comparison.expressions[0].data_type == DataType::String;
 
     if comparison_expressions_are_strings && comparison.expressions.len() > 1 {
-        return to_code_str_comparison_of_string_expressions(comparison);
+        return to_code_str_comparison_of_string_expressions(comparison, line);
     }
 
-    code_str.push_str(&to_code_str_expr(&comparison.expressions[0]));
+    code_str.push_str(&to_code_str_expr(&comparison.expressions[0], line));
 
     for (idx, expr) in comparison.expressions.iter().skip(1).enumerate() {
         if idx < comparison.operators.len() {
@@ -246,55 +695,35 @@ pub fn to_code_str_comparison(comparison: &Comparison) -> String {
                 comparison_operator_to_str(&comparison.operators[idx])
             ));
         }
-        code_str.push_str(&to_code_str_expr(expr));
+        code_str.push_str(&to_code_str_expr(expr, line));
     }
 
     code_str
 }
 
-fn to_code_str_comparison_of_string_expressions(comparison: &Comparison) -> String {
+/// Chained comparisons are rejected by the analyzer, so a string comparison
+/// is always exactly two expressions and one operator.
+/// strcmp returns <0, 0 or >0, so comparing its result against 0 with the same
+/// operator gives us alphabetical (byte by byte) ordering.
+fn to_code_str_comparison_of_string_expressions(comparison: &Comparison, line: u32) -> String {
     let mut code_str = String::new();
-    for (idx, expression) in comparison.expressions.iter().enumerate() {
-        if idx == comparison.expressions.len() - 1 {
-            //stop here
-            break;
-        }
 
-        // Get the comparison operator for this pair
-        let op = if idx < comparison.operators.len() {
-            &comparison.operators[idx]
-        } else {
-            &ComparisonOperator::equalequal // Default fallback
-        };
+    code_str.push_str("(strcmp(");
+    code_str.push_str(&to_code_str_expr(&comparison.expressions[0], line));
+    code_str.push_str(", ");
+    code_str.push_str(&to_code_str_expr(&comparison.expressions[1], line));
+    code_str.push_str(") ");
 
-        code_str.push_str("(strcmp(");
-        code_str.push_str(&to_code_str_expr(expression));
-        code_str.push_str(", ");
-        code_str.push_str(&to_code_str_expr(&comparison.expressions[idx + 1]));
-        code_str.push_str(") ");
-
-        match op {
-            ComparisonOperator::equalequal => code_str.push_str("== 0"),
-            ComparisonOperator::notequal => code_str.push_str("!= 0"),
-            _ => code_str.push_str("ERROR INCORRECT OPERATOR"), // Default to equality
-        }
-
-        code_str.push_str(")");
-
-        // TODO: incorporate !=, rn its only ==
-        if idx < comparison.expressions.len() - 2 {
-            code_str.push_str(" && "); //purely for debugging readability
-            //code_str.push_str(comparison_operator_to_str(&comparison.operators[idx]));
-        }
-    }
+    code_str.push_str(comparison_operator_to_str(&comparison.operators[0]));
+    code_str.push_str(" 0)");
     code_str
 }
 
-pub fn to_code_str_logical(logical: &crate::ast::Logical) -> String {
+pub fn to_code_str_logical(logical: &crate::ast::Logical, line: u32) -> String {
     let mut code_str = String::new();
 
     if !logical.comparisons.is_empty() {
-        code_str.push_str(&to_code_str_comparison(&logical.comparisons[0]));
+        code_str.push_str(&to_code_str_comparison(&logical.comparisons[0], line));
 
         for (idx, comparison) in logical.comparisons.iter().skip(1).enumerate() {
             if idx < logical.operators.len() {
@@ -303,7 +732,7 @@ pub fn to_code_str_logical(logical: &crate::ast::Logical) -> String {
                     logical_operator_to_str(&logical.operators[idx])
                 ));
             }
-            code_str.push_str(&to_code_str_comparison(comparison));
+            code_str.push_str(&to_code_str_comparison(comparison, line));
         }
     }
 
@@ -312,18 +741,19 @@ pub fn to_code_str_logical(logical: &crate::ast::Logical) -> String {
 
 // Helper functions to convert operators to string representations
 
-fn expression_operator_to_str(op: &ExpressionOperator) -> &'static str {
+fn expression_operator_to_runtime_fn(op: &ExpressionOperator) -> &'static str {
     match op {
-        ExpressionOperator::Plus => "+",
-        ExpressionOperator::Minus => "-",
+        ExpressionOperator::Plus => "plank_add",
+        ExpressionOperator::Minus => "plank_sub",
         ExpressionOperator::invalidop => "/* invalid op */",
     }
 }
 
-fn term_operator_to_str(op: &TermOperator) -> &'static str {
+fn term_operator_to_runtime_fn(op: &TermOperator) -> &'static str {
     match op {
-        TermOperator::Multiply => "*",
-        TermOperator::Divide => "/",
+        TermOperator::Multiply => "plank_mul",
+        TermOperator::Divide => "plank_div",
+        TermOperator::Modulo => "plank_mod",
         TermOperator::invalidop => "/* invalid op */",
     }
 }
@@ -344,48 +774,156 @@ fn logical_operator_to_str(op: &LogicalOperator) -> &'static str {
     match op {
         LogicalOperator::And => "&&",
         LogicalOperator::Or => "||",
-        LogicalOperator::Not => "!",
         LogicalOperator::invalidop => "/* invalid op */",
     }
 }
 
 fn to_code_str_print(print_stmt: &PrintStatement) -> String {
-    let mut expr_str = to_code_str_logical(&print_stmt.logical);
-
-    // TODO: The data type the expr evaluates to should be resolved by now, and
-    // we can look at it and adjust the printf statement accordingly.
-    // TODO: change this depending on ln flag later
-    //let newline_or_empty = print_stmt.is_print_ln ? "\n" : "";
-    let mut newline_or_empty = String::new();
-    if print_stmt.is_print_ln {
-        newline_or_empty.push_str("\n");
-    }
-
-    expr_str = match print_stmt.logical.data_type {
-        DataType::Number => format!("printf(\"%d\",{});", expr_str),
-        DataType::String => format!("printf({});", expr_str),
-        // TODO: change this to print string of boolean
-        DataType::Boolean => format!("printf(\"%s\", {} ? \"true\" : \"false\");", expr_str),
-        _ => "not either of these".to_string(),
+    let stream = if print_stmt.to_stderr {
+        "stderr"
+    } else {
+        "stdout"
     };
+    let mut code_str = String::new();
+
+    // The separator is worked out once, before anything is printed.
+    let separator_str = match &print_stmt.separator {
+        Some(separator) => {
+            code_str.push_str(&format!(
+                "{{ {} plank_print_separator = {};\n",
+                DataType::String,
+                to_code_str_logical(separator, print_stmt.line)
+            ));
+            format!("fprintf({}, \"%s\", plank_print_separator);\n", stream)
+        }
+        None => format!("fprintf({}, \" \");\n", stream),
+    };
+
+    let last_idx = print_stmt.logicals.len().saturating_sub(1);
+    for (i, logical) in print_stmt.logicals.iter().enumerate() {
+        if i > 0 {
+            code_str.push_str(&separator_str);
+        }
+        let newline = print_stmt.is_print_ln && i == last_idx;
+        code_str.push_str(&to_code_str_print_logical(
+            logical,
+            stream,
+            newline,
+            print_stmt.line,
+        ));
+        code_str.push('\n');
+    }
+
+    // println() on its own just ends the line.
+    if print_stmt.is_print_ln && print_stmt.logicals.is_empty() {
+        code_str.push_str(&format!("fprintf({}, \"\\n\");\n", stream));
+    }
+    if print_stmt.separator.is_some() {
+        code_str.push_str("}\n");
+    }
+
+    code_str.push('\n'); // purely for readability while debugging
+    code_str
+}
+
+/// Prints a single argument of print, based on the type it resolved to.
+/// newline ends the line after it (for the last argument of println).
+fn to_code_str_print_logical(logical: &Logical, stream: &str, newline: bool, line: u32) -> String {
+    // An interpolated string is written out with a single printf.
+    if let [comparison] = logical.comparisons.as_slice()
+        && let [expression] = comparison.expressions.as_slice()
+        && let Some(value) = expression.as_single_value()
+        && value.value_type == ValueType::InterpolatedString
+    {
+        let (mut format_str, arguments) = to_code_str_format(value, line);
+        if newline {
+            format_str.push_str("\\n");
+        }
+        let mut code_str = format!("fprintf({}, \"{}\"", stream, format_str);
+        for argument in arguments {
+            code_str.push_str(", ");
+            code_str.push_str(&argument);
+        }
+        code_str.push_str(");");
+        return code_str;
+    }
 
-    if print_stmt.is_print_ln {
-        expr_str.push_str("printf(\"\\n\");\n");
+    let mut code_str = to_code_str_printf(
+        &to_code_str_logical(logical, line),
+        &logical.data_type,
+        stream,
+        0,
+    );
+    if newline {
+        code_str.push_str(&format!(" fprintf({}, \"\\n\");", stream));
     }
+    code_str
+}
 
-    expr_str.push_str("\n"); // purely for readability while debugging
-    expr_str
+/// stream is the c FILE* to print to (stdout, stderr, or the stream a record's
+/// print function was given).
+/// depth is how many lists deep we are, so that nested lists get their own
+/// loop variables.
+fn to_code_str_printf(value_str: &str, data_type: &DataType, stream: &str, depth: usize) -> String {
+    match data_type {
+        // A literal is an int in c, so it's cast to match the format.
+        DataType::Number => format!(
+            "fprintf({}, \"%\" PRId64, (int64_t)({}));",
+            stream, value_str
+        ),
+        DataType::String => format!("fprintf({}, \"%s\", {});", stream, value_str),
+        DataType::Boolean => format!(
+            "fprintf({}, \"%s\", {} ? \"true\" : \"false\");",
+            stream, value_str
+        ),
+        DataType::List(element_type) => {
+            to_code_str_print_list(value_str, element_type, stream, depth)
+        }
+        DataType::Record(name) => format!("plank_print_{}({}, {});", name, stream, value_str),
+        DataType::Enum(name) => format!(
+            "fprintf({}, \"%s\", plank_{}_names[{}]);",
+            stream, name, value_str
+        ),
+        _ => "not either of these".to_string(),
+    }
+}
+
+/// Lists print like [1, 2, 3]; every element is printed the same way it would
+/// be printed on its own.
+fn to_code_str_print_list(
+    list_str: &str,
+    element_type: &DataType,
+    stream: &str,
+    depth: usize,
+) -> String {
+    let list_var = format!("plank_print_list_{}", depth);
+    let idx_var = format!("plank_print_idx_{}", depth);
+    let element_str = format!("(({}*){}->items)[{}]", element_type, list_var, idx_var);
+
+    format!(
+        "{{ PlankList* {list_var} = {list_str}; fprintf({stream}, \"[\"); \
+         for (int64_t {idx_var} = 0; {idx_var} < {list_var}->length; {idx_var}++) {{ \
+         if ({idx_var} > 0) fprintf({stream}, \", \"); {} }} \
+         fprintf({stream}, \"]\"); }}",
+        to_code_str_printf(&element_str, element_type, stream, depth + 1)
+    )
 }
 
 fn to_code_str_if(if_stmt: &IfStatement) -> String {
     let mut code_str = String::new();
 
-    let condition_str = to_code_str_logical(&if_stmt.condition);
-    code_str.push_str(&format!("if ({}) {{\n", condition_str));
+    for (idx, arm) in if_stmt.arms.iter().enumerate() {
+        let condition_str = to_code_str_logical(&arm.condition, arm.line_declared_on);
+        if idx == 0 {
+            code_str.push_str(&format!("if ({}) {{\n", condition_str));
+        } else {
+            code_str.push_str(&format!("}} else if ({}) {{\n", condition_str));
+        }
 
-    for statement in &if_stmt.if_body {
-        code_str.push_str("   ");
-        code_str.push_str(&to_code_str(statement));
+        for statement in &arm.body {
+            code_str.push_str("   ");
+            code_str.push_str(&to_code_str(statement));
+        }
     }
 
     if let Some(else_body) = &if_stmt.else_body {
@@ -403,7 +941,7 @@ fn to_code_str_if(if_stmt: &IfStatement) -> String {
 fn to_code_str_while(while_stmt: &WhileStatement) -> String {
     let mut code_str = String::new();
 
-    let condition_str = to_code_str_logical(&while_stmt.condition);
+    let condition_str = to_code_str_logical(&while_stmt.condition, while_stmt.line_declared_on);
     code_str.push_str(&format!("while ({}) {{\n", condition_str));
 
     for statement in &while_stmt.body {
@@ -412,11 +950,187 @@ fn to_code_str_while(while_stmt: &WhileStatement) -> String {
     }
 
     code_str.push_str("}\n");
+    code_str.push_str(&to_code_str_loop_exit_label(&while_stmt.exit_label));
+    code_str
+}
+
+/// Label right after a loop, for breaks that can't use a c break (see BreakStatement).
+fn to_code_str_loop_exit_label(exit_label: &Option<String>) -> String {
+    match exit_label {
+        Some(label) => format!("{}:;\n", label),
+        None => String::new(),
+    }
+}
+
+fn to_code_str_break(break_stmt: &BreakStatement) -> String {
+    match &break_stmt.exit_label {
+        Some(label) => format!("goto {};\n", label),
+        None => "break;\n".to_string(),
+    }
+}
+
+fn to_code_str_match(match_stmt: &MatchStatement) -> String {
+    match match_stmt.scrutinee.data_type {
+        DataType::String => to_code_str_match_string(match_stmt),
+        _ => to_code_str_match_number(match_stmt),
+    }
+}
+
+/// Number and enum matches are a c switch. Every case gets its own block (so variables
+/// declared in it don't leak into the next case) and ends with a break, since
+/// plank cases don't fall through.
+fn to_code_str_match_number(match_stmt: &MatchStatement) -> String {
+    let mut code_str = String::new();
+    let scrutinee_str = to_code_str_logical(&match_stmt.scrutinee, match_stmt.line_declared_on);
+    code_str.push_str(&format!("switch ({}) {{\n", scrutinee_str));
+
+    // c doesn't allow the same case label twice; the analyzer already warned
+    // about duplicates, the first one wins.
+    let mut seen_values: Vec<Literal> = Vec::new();
+    for case in &match_stmt.cases {
+        let mut labels_str = String::new();
+        for value in &case.values {
+            let Some(literal) = value.as_literal() else {
+                continue;
+            };
+            if seen_values.contains(&literal) {
+                continue;
+            }
+            match &literal {
+                Literal::Number(number) => labels_str.push_str(&format!("case {}:\n", number)),
+                Literal::EnumVariant { enum_name, variant } => labels_str.push_str(&format!(
+                    "case {}:\n",
+                    to_code_str_enum_variant(enum_name, variant)
+                )),
+                _ => continue,
+            }
+            seen_values.push(literal);
+        }
+        if labels_str.is_empty() {
+            continue;
+        }
+
+        code_str.push_str(&labels_str);
+        code_str.push_str(&to_code_str_match_body(&case.body));
+    }
+
+    if let Some(default_body) = &match_stmt.default_body {
+        code_str.push_str("default:\n");
+        code_str.push_str(&to_code_str_match_body(default_body));
+    }
+
+    code_str.push_str("}\n");
+    code_str
+}
+
+fn to_code_str_match_body(body: &[Statement]) -> String {
+    let mut code_str = String::from("{\n");
+    for statement in body {
+        code_str.push_str("   ");
+        code_str.push_str(&to_code_str(statement));
+    }
+    code_str.push_str("   break;\n}\n");
+    code_str
+}
+
+/// c can't switch on strings, so String matches are an if/else if chain of
+/// strcmps. The scrutinee is stored first so it's only evaluated once.
+fn to_code_str_match_string(match_stmt: &MatchStatement) -> String {
+    let mut code_str = String::new();
+    let scrutinee_str = to_code_str_logical(&match_stmt.scrutinee, match_stmt.line_declared_on);
+    code_str.push_str(&format!(
+        "{{\nchar* plank_match_value = {};\n",
+        scrutinee_str
+    ));
+
+    for (idx, case) in match_stmt.cases.iter().enumerate() {
+        let condition_str = case
+            .values
+            .iter()
+            .map(|value| {
+                format!(
+                    "strcmp(plank_match_value, {}) == 0",
+                    to_code_str_logical(value, case.line_declared_on)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" || ");
+
+        if idx == 0 {
+            code_str.push_str(&format!("if ({}) {{\n", condition_str));
+        } else {
+            code_str.push_str(&format!("}} else if ({}) {{\n", condition_str));
+        }
+        for statement in &case.body {
+            code_str.push_str("   ");
+            code_str.push_str(&to_code_str(statement));
+        }
+    }
+
+    if let Some(default_body) = &match_stmt.default_body {
+        // A match with only a default still needs something to attach it to.
+        if match_stmt.cases.is_empty() {
+            code_str.push_str("{\n");
+        } else {
+            code_str.push_str("} else {\n");
+        }
+        for statement in default_body {
+            code_str.push_str("   ");
+            code_str.push_str(&to_code_str(statement));
+        }
+        code_str.push_str("}\n");
+    } else if !match_stmt.cases.is_empty() {
+        code_str.push_str("}\n");
+    }
+
+    code_str.push_str("}\n");
+    code_str
+}
+
+/// The end and step of the range are only evaluated once, before the loop
+/// starts, so they're stored in their own variables next to the loop variable.
+fn to_code_str_for(for_stmt: &ForStatement) -> String {
+    let mut code_str = String::new();
+    let line = for_stmt.line_declared_on;
+    let var = &for_stmt.var_name;
+    let end_var = format!("plank_end_{}", var);
+    let step_var = format!("plank_step_{}", var);
+
+    let start_str = to_code_str_logical(&for_stmt.start, line);
+    let end_str = to_code_str_logical(&for_stmt.end, line);
+
+    match &for_stmt.step {
+        Some(step) => {
+            // The step can be negative (counting down), so which way we
+            // compare against the end depends on its sign. plank_for_next
+            // won't step past the end.
+            let step_str = to_code_str_logical(step, line);
+            code_str.push_str(&format!(
+                "for (int64_t {var} = {start_str}, {end_var} = {end_str}, {step_var} = plank_for_step({step_str}, {line}); \
+                 ({step_var} > 0 ? {var} < {end_var} : {var} > {end_var}); \
+                 {var} = plank_for_next({var}, {end_var}, {step_var})) {{\n"
+            ));
+        }
+        None => {
+            code_str.push_str(&format!(
+                "for (int64_t {var} = {start_str}, {end_var} = {end_str}; {var} < {end_var}; {var}++) {{\n"
+            ));
+        }
+    }
+
+    for statement in &for_stmt.body {
+        code_str.push_str("   ");
+        code_str.push_str(&to_code_str(statement));
+    }
+
+    code_str.push_str("}\n");
+    code_str.push_str(&to_code_str_loop_exit_label(&for_stmt.exit_label));
     code_str
 }
 
 fn to_code_str_raw_function_call(stmt: &RawFunctionCallStatement) -> String {
-    let mut code_str = to_code_str_function_call(&stmt.value);
+    // Could also be a record construction.
+    let mut code_str = to_code_str_value(&stmt.value, stmt.line);
     code_str.push_str(";\n");
     code_str
 }
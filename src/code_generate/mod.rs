@@ -1,25 +1,73 @@
 mod convert_statement;
+mod runtime;
 
-use crate::ast::{FunctionDeclarationStatement, FunctionSymbol, FunctionTable, Statement};
+use std::collections::HashSet;
+
+use crate::ast::{
+    FunctionDeclarationStatement, FunctionSymbol, FunctionTable, Statement, TypeTable, ValueType,
+    visit_statements,
+};
+use crate::builtins::BUILTIN_FUNCTIONS;
+use crate::call_graph::CallGraph;
 pub use convert_statement::{
-    convert_function_header_to_code_str, to_code_str, to_code_str_func_decl_stmt,
+    convert_function_header_to_code_str, to_code_str, to_code_str_depth_limited_func_decl_stmt,
+    to_code_str_func_decl_stmt, to_code_str_global_decl, to_code_str_global_init,
+    to_code_str_user_types,
 };
+use runtime::runtime_helpers_code_str;
 
 /**
  * Converts an AST into c code equivalent (in the form of a string).
+ * runtime_checks controls whether the generated program checks for integer
+ * overflow and division by zero (disabled for release builds).
+ * max_recursion_depth, when given, stops the program with a runtime error once
+ * recursive functions go that many calls deep.
  */
-pub fn generate_code_str(ast_vec: &Vec<Statement>, function_defs: &FunctionTable) -> String {
+pub fn generate_code_str(
+    ast_vec: &Vec<Statement>,
+    function_defs: &FunctionTable,
+    type_table: &TypeTable,
+    runtime_checks: bool,
+    max_recursion_depth: Option<u32>,
+) -> String {
     let mut code_str = String::new();
 
     code_str.push_str("#include <stdio.h>\n");
     code_str.push_str("#include <stdlib.h>\n");
     code_str.push_str("#include <string.h>\n");
     code_str.push_str("#include <stdbool.h>\n");
+    code_str.push_str("#include <stdint.h>\n");
+    code_str.push_str("#include <inttypes.h>\n");
+    code_str.push_str("#include <errno.h>\n");
+    code_str.push_str("#include <ctype.h>\n");
+    code_str.push_str("#include <stdarg.h>\n");
     code_str.push_str("\n");
 
+    code_str.push_str(&runtime_helpers_code_str(
+        runtime_checks,
+        max_recursion_depth,
+        &get_used_builtins(ast_vec),
+    ));
+
+    // Records and enums need to be defined before anything uses them.
+    code_str.push_str(&to_code_str_user_types(type_table));
+
+    // Constants and globals are at file scope so functions can use them.
+    // Globals are only declared here; they're set in main, in order.
+    for statement in ast_vec {
+        if let Statement::VariableDeclaration(var_decl) = statement {
+            if var_decl.is_const {
+                code_str.push_str(&to_code_str(statement));
+            } else {
+                code_str.push_str(&to_code_str_global_decl(var_decl));
+            }
+        }
+    }
+
     // user function c headers
     for function_def in function_defs.get_all_defs() {
-        code_str.push_str(&convert_function_header_to_code_str(function_def));
+        let c_name = function_defs.get_c_name(function_def);
+        code_str.push_str(&convert_function_header_to_code_str(function_def, &c_name));
         code_str.push_str(";\n");
     }
     code_str.push_str("\n");
@@ -35,6 +83,11 @@ pub fn generate_code_str(ast_vec: &Vec<Statement>, function_defs: &FunctionTable
             Statement::FunctionDeclaration(func_decl_statement) => {
                 func_declaration_statements.push(func_decl_statement);
             }
+            // Already put above main.
+            Statement::VariableDeclaration(var_decl) if var_decl.is_const => (),
+            Statement::VariableDeclaration(var_decl) => {
+                code_str.push_str(&to_code_str_global_init(var_decl));
+            }
             _ => {
                 code_str.push_str(&to_code_str(statement));
             }
@@ -44,10 +97,21 @@ pub fn generate_code_str(ast_vec: &Vec<Statement>, function_defs: &FunctionTable
     code_str.push_str("return 0;\n");
     code_str.push_str("}\n");
 
+    let call_graph = CallGraph::new(ast_vec, function_defs);
     for func_decl in func_declaration_statements {
-        let function_def = function_defs.get_func_def_using_str(&func_decl.function_name);
+        let function_def =
+            function_defs.get_func_def(&func_decl.function_name, &func_decl.parameter_types());
         match function_def {
-            Some(def) => code_str.push_str(&to_code_str_func_decl_stmt(func_decl, def)),
+            Some(def) => {
+                let c_name = function_defs.get_c_name(def);
+                if max_recursion_depth.is_some() && call_graph.is_recursive(&c_name) {
+                    code_str.push_str(&to_code_str_depth_limited_func_decl_stmt(
+                        func_decl, def, &c_name,
+                    ));
+                } else {
+                    code_str.push_str(&to_code_str_func_decl_stmt(func_decl, def, &c_name));
+                }
+            }
             None => {
                 // TODO: this should never be reached right
             }
@@ -56,3 +120,29 @@ pub fn generate_code_str(ast_vec: &Vec<Statement>, function_defs: &FunctionTable
 
     code_str
 }
+
+/// The builtins called anywhere in the program (including in functions), so
+/// only their runtime helpers are put in the c file.
+fn get_used_builtins(statements: &[Statement]) -> Vec<&'static str> {
+    let mut called = HashSet::new();
+    add_functions_called(statements, &mut called);
+
+    BUILTIN_FUNCTIONS
+        .iter()
+        .map(|builtin| builtin.name)
+        .filter(|name| called.contains(*name))
+        .collect()
+}
+
+fn add_functions_called<'a>(statements: &'a [Statement], called: &mut HashSet<&'a str>) {
+    visit_statements(statements, &mut |statement| {
+        statement.visit_values(&mut |value| {
+            if value.value_type == ValueType::FunctionCall {
+                called.insert(value.raw_text.as_str());
+            }
+        });
+        if let Statement::FunctionDeclaration(func_decl) = statement {
+            add_functions_called(&func_decl.body, called);
+        }
+    });
+}
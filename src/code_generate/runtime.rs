@@ -0,0 +1,338 @@
+// C helper functions that generated plank programs rely on at runtime.
+// These are emitted once at the top of the c file, right after the includes.
+
+/// Returns the c source for the runtime helpers.
+/// When runtime_checks is false (release builds), the arithmetic helpers skip
+/// overflow/division checks and just wrap around on overflow, and list
+/// accesses skip bounds checks.
+/// max_recursion_depth adds the helper that recursive functions use to stop
+/// the program before it overflows the stack.
+/// used_builtins are the names of the builtins the program calls.
+pub fn runtime_helpers_code_str(
+    runtime_checks: bool,
+    max_recursion_depth: Option<u32>,
+    used_builtins: &[&str],
+) -> String {
+    let mut code_str = String::new();
+
+    code_str.push_str(RUNTIME_ERROR_HELPER);
+    code_str.push_str(LIST_HELPERS);
+    code_str.push_str(FOR_NEXT_HELPER);
+    code_str.push_str(FORMAT_HELPER);
+
+    if let Some(max_depth) = max_recursion_depth {
+        code_str.push_str(&recursion_limit_helper_code_str(max_depth));
+    }
+
+    if runtime_checks {
+        code_str.push_str(CHECKED_ARITHMETIC_HELPERS);
+        code_str.push_str(CHECKED_LIST_ACCESS_HELPER);
+    } else {
+        code_str.push_str(UNCHECKED_ARITHMETIC_HELPERS);
+        code_str.push_str(UNCHECKED_LIST_ACCESS_HELPER);
+    }
+
+    // After the arithmetic helpers, which some of them use.
+    for name in used_builtins {
+        if let Some(helper) = builtin_helper_code_str(name) {
+            code_str.push_str(helper);
+        }
+    }
+
+    code_str.push('\n');
+    code_str
+}
+
+/// Every runtime error goes through here so they're all tagged with the plank
+/// line that caused them.
+const RUNTIME_ERROR_HELPER: &str = r#"static void plank_runtime_error(int line, const char* message) {
+   fprintf(stderr, "Runtime Error: %s (line %d)\n", message, line);
+   exit(1);
+}
+"#;
+
+/// Counts how many calls to recursive functions are currently running; line
+/// is where the function is declared.
+fn recursion_limit_helper_code_str(max_depth: u32) -> String {
+    format!(
+        r#"static int64_t plank_recursion_depth = 0;
+static void plank_enter_recursion(const char* function_name, int line) {{
+   plank_recursion_depth++;
+   if (plank_recursion_depth > {max_depth}) {{
+      char message[200];
+      snprintf(message, sizeof(message), "stack overflow; recursion through '%s' went more than {max_depth} calls deep", function_name);
+      plank_runtime_error(line, message);
+   }}
+}}
+"#
+    )
+}
+
+/// Every List is a PlankList*, no matter its element type; the items are cast
+/// to the element type when they're accessed.
+/// Lists are never freed.
+const LIST_HELPERS: &str = r#"typedef struct {
+   int64_t length;
+   size_t item_size;
+   void* items;
+} PlankList;
+static PlankList* plank_list_new(int64_t length, size_t item_size, const void* items) {
+   PlankList* list = malloc(sizeof(PlankList));
+   list->length = length;
+   list->item_size = item_size;
+   list->items = malloc(item_size * length);
+   memcpy(list->items, items, item_size * length);
+   return list;
+}
+"#;
+
+/// Moves a stepped for loop's variable along. A step that would go past the
+/// end stops at the end instead, so a loop near the edge of the Number range
+/// finishes instead of overflowing. Only called while the loop hasn't reached
+/// its end, so the distance left always fits in a uint64_t.
+const FOR_NEXT_HELPER: &str = r#"static int64_t plank_for_next(int64_t var, int64_t end, int64_t step) {
+   uint64_t remaining = step > 0 ? (uint64_t)end - (uint64_t)var : (uint64_t)var - (uint64_t)end;
+   uint64_t distance = step > 0 ? (uint64_t)step : -(uint64_t)step;
+   if (distance >= remaining) return end;
+   return (int64_t)((uint64_t)var + (uint64_t)step);
+}
+"#;
+
+/// Builds the String for an interpolated string ("i is {i}") that isn't
+/// printed right away. The strings it makes are never freed.
+const FORMAT_HELPER: &str = r#"static char* plank_format(const char* format, ...) {
+   va_list args;
+   va_start(args, format);
+   int length = vsnprintf(NULL, 0, format, args);
+   va_end(args);
+   char* result = malloc(length + 1);
+   va_start(args, format);
+   vsnprintf(result, length + 1, format, args);
+   va_end(args);
+   return result;
+}
+"#;
+
+// Returns a pointer to the element, so it can be read or assigned to.
+const CHECKED_LIST_ACCESS_HELPER: &str = r#"static void* plank_list_at(PlankList* list, int64_t index, int line) {
+   if (index < 0 || index >= list->length) {
+      char message[100];
+      snprintf(message, sizeof(message), "list index %" PRId64 " is out of bounds (length %" PRId64 ")", index, list->length);
+      plank_runtime_error(line, message);
+   }
+   return (char*)list->items + index * list->item_size;
+}
+"#;
+
+const UNCHECKED_LIST_ACCESS_HELPER: &str = r#"static inline void* plank_list_at(PlankList* list, int64_t index, int line) {
+   return (char*)list->items + index * list->item_size;
+}
+"#;
+
+const CHECKED_ARITHMETIC_HELPERS: &str = r#"static int64_t plank_add(int64_t a, int64_t b, int line) {
+   int64_t result;
+   if (__builtin_add_overflow(a, b, &result)) plank_runtime_error(line, "integer overflow in addition");
+   return result;
+}
+static int64_t plank_sub(int64_t a, int64_t b, int line) {
+   int64_t result;
+   if (__builtin_sub_overflow(a, b, &result)) plank_runtime_error(line, "integer overflow in subtraction");
+   return result;
+}
+static int64_t plank_mul(int64_t a, int64_t b, int line) {
+   int64_t result;
+   if (__builtin_mul_overflow(a, b, &result)) plank_runtime_error(line, "integer overflow in multiplication");
+   return result;
+}
+static int64_t plank_div(int64_t a, int64_t b, int line) {
+   if (b == 0) plank_runtime_error(line, "division by zero");
+   if (a == INT64_MIN && b == -1) plank_runtime_error(line, "integer overflow in division");
+   return a / b;
+}
+static int64_t plank_neg(int64_t a, int line) {
+   if (a == INT64_MIN) plank_runtime_error(line, "integer overflow in negation");
+   return -a;
+}
+static int64_t plank_mod(int64_t a, int64_t b, int line) {
+   if (b == 0) plank_runtime_error(line, "division by zero in modulo");
+   if (b == -1) return 0;
+   return a % b;
+}
+static int64_t plank_for_step(int64_t step, int line) {
+   if (step == 0) plank_runtime_error(line, "for loop step can't be 0");
+   return step;
+}
+static int64_t plank_pow(int64_t base, int64_t exponent, int line) {
+   if (exponent < 0) plank_runtime_error(line, "negative exponent");
+   int64_t result = 1;
+   while (exponent > 0) {
+      if ((exponent & 1) && __builtin_mul_overflow(result, base, &result)) plank_runtime_error(line, "integer overflow in exponent");
+      exponent >>= 1;
+      if (exponent > 0 && __builtin_mul_overflow(base, base, &base)) plank_runtime_error(line, "integer overflow in exponent");
+   }
+   return result;
+}
+"#;
+
+// Unsigned math so that overflow wraps instead of being undefined behavior.
+const UNCHECKED_ARITHMETIC_HELPERS: &str = r#"static inline int64_t plank_add(int64_t a, int64_t b, int line) {
+   return (int64_t)((uint64_t)a + (uint64_t)b);
+}
+static inline int64_t plank_sub(int64_t a, int64_t b, int line) {
+   return (int64_t)((uint64_t)a - (uint64_t)b);
+}
+static inline int64_t plank_mul(int64_t a, int64_t b, int line) {
+   return (int64_t)((uint64_t)a * (uint64_t)b);
+}
+static inline int64_t plank_div(int64_t a, int64_t b, int line) {
+   return b == -1 ? (int64_t)(0 - (uint64_t)a) : a / b;
+}
+static inline int64_t plank_neg(int64_t a, int line) {
+   return (int64_t)(0 - (uint64_t)a);
+}
+static inline int64_t plank_mod(int64_t a, int64_t b, int line) {
+   return b == -1 ? 0 : a % b;
+}
+static inline int64_t plank_for_step(int64_t step, int line) {
+   return step;
+}
+static inline int64_t plank_pow(int64_t base, int64_t exponent, int line) {
+   uint64_t result = 1;
+   uint64_t b = (uint64_t)base;
+   for (; exponent > 0; exponent >>= 1) {
+      if (exponent & 1) result *= b;
+      b *= b;
+   }
+   return (int64_t)result;
+}
+"#;
+
+/// The c for a builtin (see builtins.rs), or None if it's written inline where
+/// it's called. Only the builtins a program calls are put in its c file.
+/// The strings these make are never freed.
+fn builtin_helper_code_str(name: &str) -> Option<&'static str> {
+    let code_str = match name {
+        "abs" => ABS_HELPER,
+        "min" => MIN_HELPER,
+        "max" => MAX_HELPER,
+        "substring" => SUBSTRING_HELPER,
+        "contains" => CONTAINS_HELPER,
+        "upper" => UPPER_HELPER,
+        "lower" => LOWER_HELPER,
+        "trim" => TRIM_HELPER,
+        "toString" => TO_STRING_HELPER,
+        "toNumber" => TO_NUMBER_HELPER,
+        "toBoolean" => TO_BOOLEAN_HELPER,
+        "exit" => EXIT_HELPER,
+        _ => return None,
+    };
+    Some(code_str)
+}
+
+// Goes through plank_neg, so abs of the smallest Number is an overflow (when
+// runtime checks are on).
+const ABS_HELPER: &str = r#"static int64_t plank_abs(int64_t number, int line) {
+   return number < 0 ? plank_neg(number, line) : number;
+}
+"#;
+
+const MIN_HELPER: &str = r#"static int64_t plank_min(int64_t a, int64_t b) {
+   return a < b ? a : b;
+}
+"#;
+
+const MAX_HELPER: &str = r#"static int64_t plank_max(int64_t a, int64_t b) {
+   return a > b ? a : b;
+}
+"#;
+
+const SUBSTRING_HELPER: &str = r#"static char* plank_substring(const char* text, int64_t start, int64_t end, int line) {
+   int64_t length = (int64_t)strlen(text);
+   if (start < 0 || end < start || end > length) {
+      char message[120];
+      snprintf(message, sizeof(message), "substring from %" PRId64 " to %" PRId64 " is out of bounds (length %" PRId64 ")", start, end, length);
+      plank_runtime_error(line, message);
+   }
+   char* result = malloc(end - start + 1);
+   memcpy(result, text + start, end - start);
+   result[end - start] = '\0';
+   return result;
+}
+"#;
+
+const CONTAINS_HELPER: &str = r#"static bool plank_contains(const char* text, const char* part) {
+   return strstr(text, part) != NULL;
+}
+"#;
+
+const UPPER_HELPER: &str = r#"static char* plank_upper(const char* text) {
+   size_t length = strlen(text);
+   char* result = malloc(length + 1);
+   for (size_t i = 0; i <= length; i++) result[i] = (char)toupper((unsigned char)text[i]);
+   return result;
+}
+"#;
+
+const LOWER_HELPER: &str = r#"static char* plank_lower(const char* text) {
+   size_t length = strlen(text);
+   char* result = malloc(length + 1);
+   for (size_t i = 0; i <= length; i++) result[i] = (char)tolower((unsigned char)text[i]);
+   return result;
+}
+"#;
+
+const TRIM_HELPER: &str = r#"static char* plank_trim(const char* text) {
+   while (isspace((unsigned char)*text)) text++;
+   size_t length = strlen(text);
+   while (length > 0 && isspace((unsigned char)text[length - 1])) length--;
+   char* result = malloc(length + 1);
+   memcpy(result, text, length);
+   result[length] = '\0';
+   return result;
+}
+"#;
+
+// Only used for Numbers; the other types are written inline.
+const TO_STRING_HELPER: &str = r#"static char* plank_to_string(int64_t number) {
+   char* str = malloc(21);
+   snprintf(str, 21, "%" PRId64, number);
+   return str;
+}
+"#;
+
+// A string that isn't exactly a Number is a runtime error, instead of quietly
+// becoming 0.
+const TO_NUMBER_HELPER: &str = r#"static int64_t plank_to_number(const char* str, int line) {
+   char* end;
+   errno = 0;
+   long long number = strtoll(str, &end, 10);
+   if (end == str || *end != '\0' || isspace((unsigned char)str[0]) || errno == ERANGE) {
+      char message[100];
+      snprintf(message, sizeof(message), "can't turn \"%.40s\" into a Number", str);
+      plank_runtime_error(line, message);
+   }
+   return (int64_t)number;
+}
+"#;
+
+const TO_BOOLEAN_HELPER: &str = r#"static bool plank_to_boolean(const char* str, int line) {
+   if (strcmp(str, "true") == 0) return true;
+   if (strcmp(str, "false") == 0) return false;
+   char message[100];
+   snprintf(message, sizeof(message), "can't turn \"%.40s\" into a Boolean (it has to be true or false)", str);
+   plank_runtime_error(line, message);
+   return false;
+}
+"#;
+
+// The shell only sees the low 8 bits of an exit status, so anything outside
+// of 0 to 255 is a runtime error instead of silently becoming another code.
+const EXIT_HELPER: &str = r#"static void plank_exit(int64_t code, int line) {
+   if (code < 0 || code > 255) {
+      char message[80];
+      snprintf(message, sizeof(message), "exit code %" PRId64 " is not between 0 and 255", code);
+      plank_runtime_error(line, message);
+   }
+   exit((int)code);
+}
+"#;
@@ -1,5 +1,8 @@
 use crate::{
-    ast::{DataType, FunctionTable, Parameter},
+    ast::{
+        DataType, EnumSymbol, FunctionTable, Parameter, RecordSymbol, TypeTable,
+        parse_default_value,
+    },
     tokenizer::{Token, TokenType},
 };
 
@@ -12,18 +15,39 @@ pub struct FunctionHeader {
 }
 
 /**
- * Returns a FunctionTable containing all function definitions defined in a
- * tokenized plank file.
+ * Returns a FunctionTable containing all function definitions, and a TypeTable
+ * containing all record and enum definitions defined in a tokenized plank file.
  */
-pub fn gather_declarations(tokens: &[Token]) -> FunctionTable {
+pub fn gather_declarations(tokens: &[Token]) -> (FunctionTable, TypeTable) {
     let mut function_table = FunctionTable::new();
+    let mut type_table = TypeTable::new();
+
+    // Enums go first, since every other type name is assumed to be a record
+    // (see DataType::from_tokens).
     let mut idx = 0;
+    while idx < tokens.len() {
+        if tokens[idx].token_type == TokenType::Enum {
+            let (enum_symbol, new_idx) = parse_enum_declaration(tokens, idx);
+            // Duplicates are reported by the analyzer.
+            type_table.insert_enum(enum_symbol);
+            idx = new_idx;
+        } else {
+            idx += 1;
+        }
+    }
+
+    idx = 0;
 
     while idx < tokens.len() {
         let token = &tokens[idx];
 
-        if token.token_type == TokenType::FunctionDeclaration {
-            let (function_header, new_idx) = parse_function_declaration(&tokens, idx);
+        if token.token_type == TokenType::Record {
+            let (record, new_idx) = parse_record_declaration(tokens, idx, &type_table);
+            // Duplicates are reported by the analyzer.
+            type_table.insert_record(record);
+            idx = new_idx;
+        } else if token.token_type == TokenType::FunctionDeclaration {
+            let (function_header, new_idx) = parse_function_declaration(&tokens, idx, &type_table);
             function_table.insert(
                 &function_header.identifier,
                 function_header.parameters,
@@ -36,11 +60,89 @@ pub fn gather_declarations(tokens: &[Token]) -> FunctionTable {
         }
     }
 
-    function_table
+    (function_table, type_table)
+}
+
+// Errors in here are reported by the ast builder, so just take every name
+// until endEnum.
+fn parse_enum_declaration(tokens: &[Token], mut idx: usize) -> (EnumSymbol, usize) {
+    idx += 1; // skip enum keyword
+
+    let enum_name = tokens[idx].lexeme.clone();
+    let line_declared_on = tokens[idx].line_number;
+    idx += 1;
+
+    let mut variants = Vec::new();
+    while idx < tokens.len()
+        && !matches!(tokens[idx].token_type, TokenType::EndEnum | TokenType::EOF)
+    {
+        if tokens[idx].token_type == TokenType::Identity {
+            variants.push(tokens[idx].lexeme.clone());
+        }
+        idx += 1;
+    }
+
+    let enum_symbol = EnumSymbol {
+        identifier: enum_name,
+        variants,
+        line_declared_on,
+    };
+
+    (enum_symbol, idx)
+}
+
+// Errors in here are reported by the ast builder, so just skip passed anything
+// that doesn't look like a field.
+fn parse_record_declaration(
+    tokens: &[Token],
+    mut idx: usize,
+    type_table: &TypeTable,
+) -> (RecordSymbol, usize) {
+    idx += 1; // skip record keyword
+
+    let record_name = tokens[idx].lexeme.clone();
+    let line_declared_on = tokens[idx].line_number;
+    idx += 1;
+
+    idx += 1; // skip ':'
+
+    let mut fields = Vec::new();
+    while idx < tokens.len()
+        && !matches!(
+            tokens[idx].token_type,
+            TokenType::EndRecord | TokenType::EOF
+        )
+    {
+        if let Some((data_type, next_idx)) = DataType::from_tokens(tokens, idx, type_table)
+            && next_idx < tokens.len()
+            && tokens[next_idx].token_type == TokenType::Identity
+        {
+            fields.push(Parameter {
+                name: tokens[next_idx].lexeme.clone(),
+                data_type,
+                default: None,
+            });
+            idx = next_idx + 1;
+        } else {
+            idx += 1;
+        }
+    }
+
+    let record = RecordSymbol {
+        identifier: record_name,
+        fields,
+        line_declared_on,
+    };
+
+    (record, idx)
 }
 
 // TODO: need to add more security / failure handling in here.
-fn parse_function_declaration(tokens: &[Token], mut idx: usize) -> (FunctionHeader, usize) {
+fn parse_function_declaration(
+    tokens: &[Token],
+    mut idx: usize,
+    type_table: &TypeTable,
+) -> (FunctionHeader, usize) {
     idx += 1; // skip function keyword
 
     let function_name = tokens[idx].lexeme.clone();
@@ -49,8 +151,14 @@ fn parse_function_declaration(tokens: &[Token], mut idx: usize) -> (FunctionHead
     idx += 1; // skip '(', need to enforce this later
 
     let mut params = Vec::new();
-    if idx < tokens.len() && tokens[idx].token_type == TokenType::VarDeclaration {
-        let (parameters, new_idx) = parse_function_parameters(tokens, idx);
+    // Params start with their type; a record type is just an Identity.
+    if idx < tokens.len()
+        && matches!(
+            tokens[idx].token_type,
+            TokenType::VarDeclaration | TokenType::Identity
+        )
+    {
+        let (parameters, new_idx) = parse_function_parameters(tokens, idx, type_table);
         params = parameters;
         idx = new_idx;
     } else {
@@ -64,18 +172,23 @@ fn parse_function_declaration(tokens: &[Token], mut idx: usize) -> (FunctionHead
 
     idx += 1; // skip 'returns' keyword, should be validated first
 
-    // Convert string type to DataType enum
-    // TODO: could be moved into its own function elsewhere.
-    // Datatype could implement some function that does this.
+    // Convert type tokens to DataType enum
     let function_return_type = match tokens[idx].lexeme.as_str() {
-        "Number" => DataType::Number,
-        "String" => DataType::String,
-        "Boolean" => DataType::Boolean,
-        "Void" => DataType::Void,
-        "nothing" => DataType::Void,
-        _ => DataType::Invalid,
+        "Void" | "nothing" => {
+            idx += 1;
+            DataType::Void
+        }
+        _ => match DataType::from_tokens(tokens, idx, type_table) {
+            Some((data_type, next_idx)) => {
+                idx = next_idx;
+                data_type
+            }
+            None => {
+                idx += 1;
+                DataType::Invalid
+            }
+        },
     };
-    idx += 1;
 
     let header = FunctionHeader {
         identifier: function_name,
@@ -88,27 +201,33 @@ fn parse_function_declaration(tokens: &[Token], mut idx: usize) -> (FunctionHead
 }
 
 // TODO: need to add more security / failure handling in here.
-fn parse_function_parameters(tokens: &[Token], mut idx: usize) -> (Vec<Parameter>, usize) {
+fn parse_function_parameters(
+    tokens: &[Token],
+    mut idx: usize,
+    type_table: &TypeTable,
+) -> (Vec<Parameter>, usize) {
     let mut parameters = Vec::new();
 
     while idx < tokens.len() && tokens[idx].token_type != TokenType::RightParen {
-        if tokens[idx].token_type != TokenType::VarDeclaration {
+        if !matches!(
+            tokens[idx].token_type,
+            TokenType::VarDeclaration | TokenType::Identity
+        ) {
             //println!("Why isn't the next token a var declaration? Not good.");
             idx += 1;
             continue;
         }
 
-        let param_type = match tokens[idx].lexeme.as_str() {
-            // TODO: move this out somewhwere so we can use it universally
-            // across all modules.
-            "Number" => DataType::Number,
-            "String" => DataType::String,
-            "Void" => DataType::Void,
-            "Boolean" => DataType::Boolean,
-            "nothing" => DataType::Void,
-            _ => DataType::Invalid,
+        let param_type = match DataType::from_tokens(tokens, idx, type_table) {
+            Some((data_type, next_idx)) => {
+                idx = next_idx;
+                data_type
+            }
+            None => {
+                idx += 1;
+                DataType::Invalid
+            }
         };
-        idx += 1;
 
         if idx >= tokens.len() {
             break;
@@ -118,9 +237,18 @@ fn parse_function_parameters(tokens: &[Token], mut idx: usize) -> (Vec<Parameter
         idx += 1;
         //println!("param name: {}", param_name);
 
+        // String greeting = "hi"
+        let mut default = None;
+        if idx < tokens.len() && tokens[idx].token_type == TokenType::Equal {
+            let (logical, new_idx) = parse_default_value(tokens, idx + 1, type_table);
+            default = logical;
+            idx = new_idx;
+        }
+
         parameters.push(Parameter {
             name: param_name,
             data_type: param_type,
+            default,
         });
         //println!("after adding param: {:#?}", parameters);
 
@@ -13,6 +13,11 @@ use ast::build_ast;
 mod symbol_table;
 use symbol_table::SymbolTable;
 
+mod builtins;
+
+mod call_graph;
+use call_graph::CallGraph;
+
 mod first_pass;
 use first_pass::gather_declarations;
 
@@ -27,10 +32,23 @@ fn main() -> std::io::Result<()> {
     let src_path = &args[1];
     let output_path = &args[2];
     let mut debug = false;
-    for arg in &args {
+    let mut runtime_checks = true;
+    let mut max_recursion_depth = None;
+    for (idx, arg) in args.iter().enumerate() {
         if arg == "--debug" {
             debug = true;
         }
+        if arg == "--no-runtime-checks" {
+            runtime_checks = false;
+        }
+        // Either --max-recursion-depth=5 or --max-recursion-depth 5
+        if let Some(depth) = arg.strip_prefix("--max-recursion-depth=") {
+            max_recursion_depth = Some(parse_max_recursion_depth(depth));
+        }
+        if arg == "--max-recursion-depth" {
+            let depth = args.get(idx + 1).map(String::as_str).unwrap_or_default();
+            max_recursion_depth = Some(parse_max_recursion_depth(depth));
+        }
     }
     //let debug = args.len() > 3 && (args[3] == "--debug");
     //let src_path: String = String::from("./example.plank"); // for testing without compiling
@@ -43,17 +61,20 @@ fn main() -> std::io::Result<()> {
         debug_print_vec(&tokens, "Tokenizer output:");
     }
 
-    // First pass: gather all function declarations. Allows file to do
+    // First pass: gather all function, record and enum declarations. Allows file to do
     // forward declarations.
-    let function_header_map = gather_declarations(&tokens);
+    let (function_header_map, type_table) = gather_declarations(&tokens);
     if debug {
         println!("---Function header map---");
         println!("{:#?}", function_header_map);
         println!("---Function header map end---");
+        println!("---Type table---");
+        println!("{:#?}", type_table);
+        println!("---Type table end---");
     }
 
     // Second pass: generate AST given token list
-    let mut ast_context = build_ast(tokens);
+    let mut ast_context = build_ast(tokens, &type_table);
     if debug {
         debug_print_vec(&ast_context.statements, "Ast output:");
     }
@@ -70,18 +91,34 @@ fn main() -> std::io::Result<()> {
     // (There is also some type resolution done in here, since the semantic analyzer
     // keeps track of scope of available symbols/functions, so was easier to make as
     // a part of the same step)
-    let semantic_errors = analyze_statements(&mut ast_context.statements, &function_header_map);
+    let (semantic_errors, semantic_warnings) = analyze_statements(
+        &mut ast_context.statements,
+        &function_header_map,
+        &type_table,
+    );
     if debug {
         debug_print_vec(
             &ast_context.statements,
             "AST (Post type resolution / semantic analysis",
         );
+        let call_graph = CallGraph::new(&ast_context.statements, &function_header_map);
+        println!("---Call graph---");
+        println!("{:#?}", call_graph);
+        println!(
+            "Recursive functions: {:?}",
+            call_graph.get_recursive_functions()
+        );
+        println!("---Call graph end---");
     }
 
     //if debug {
     //println!("semantic errors:\n{:#?}", semantic_errors);
     //}
 
+    for warning in &semantic_warnings {
+        warning.print_warning();
+    }
+
     if !semantic_errors.is_empty() {
         semantic::print_failures_message(semantic_errors.len());
         for error in &semantic_errors {
@@ -91,7 +128,13 @@ fn main() -> std::io::Result<()> {
     }
 
     // Generate c code str with ast
-    let code = generate_code_str(&ast_context.statements, &function_header_map);
+    let code = generate_code_str(
+        &ast_context.statements,
+        &function_header_map,
+        &type_table,
+        runtime_checks,
+        max_recursion_depth,
+    );
     if debug {
         debug_print_generated_code(&code);
     }
@@ -104,6 +147,26 @@ fn main() -> std::io::Result<()> {
     Ok(())
 }
 
+/// Stops the compiler if the depth isn't a whole number above 0 (a limit of 0
+/// would stop every recursive function the first time it's called).
+fn parse_max_recursion_depth(depth: &str) -> u32 {
+    match depth.parse::<u32>() {
+        Ok(depth) if depth > 0 => depth,
+        _ => {
+            eprintln!(
+                "{} {}",
+                "✗ Error:".bold().red(),
+                format!(
+                    "--max-recursion-depth must be a whole number above 0, found '{}'",
+                    depth
+                )
+                .bold()
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
 // Debug helper functions
 fn debug_print_vec<T: std::fmt::Debug>(items: &[T], label: &str) {
     println!("{} -----------------------------------", label);
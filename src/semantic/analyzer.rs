@@ -1,38 +1,65 @@
 use crate::ast::{
-    DataType, FunctionDeclarationStatement, IfStatement, PrintStatement, RawFunctionCallStatement,
-    Statement, VariableAssignmentStatement, VariableDeclarationStatement, WhileStatement,
+    Accessor, AccessorKind, ComparisonOperator, EnumDeclarationStatement, LogicalOperator,
 };
-use crate::ast::{FunctionTable, ReturnStatement};
+use crate::ast::{
+    BreakStatement, DataType, ForStatement, FunctionDeclarationStatement, IfStatement, Literal,
+    Logical, MatchStatement, PrintStatement, RawFunctionCallStatement, Statement, ValueType,
+    VariableAssignmentStatement, VariableDeclarationStatement, WhileStatement,
+};
+use crate::ast::{FunctionTable, RecordDeclarationStatement, ReturnStatement, TypeTable};
+
+use crate::semantic::type_resolution::{resolve_accessors, resolve_logical_values, resolve_value};
 
-use crate::semantic::type_resolution::{resolve_logical_values, resolve_value};
+use crate::builtins::is_builtin_function;
+use crate::call_graph::CallGraph;
+use crate::semantic::globals::get_initialization_order_errors;
 
-use crate::semantic::validate::validate_logical;
+use crate::semantic::validate::{get_accessor_errors, validate_logical, validate_value};
 
 use crate::symbol_table::SymbolTable;
 
-use crate::semantic::SemanticError;
+use crate::semantic::{SemanticError, SemanticWarning};
 
 pub struct SemanticContext {
+    /// Only the innermost context's table is filled in; it holds the tables of
+    /// the contexts below it (see push_scope_for_new_block and pop_scope).
     pub symbol_table: SymbolTable,
     pub scope: Option<u8>, // Function scope if in function, None if global
+    /// Label that goes after the innermost loop we're in.
+    /// break/continue are only allowed when this is set.
+    pub loop_exit_label: Option<String>,
+    /// Whether we're in a case of a Number match (a c switch) inside of that
+    /// loop, where a c break would only leave the switch.
+    pub in_switch: bool,
 }
 
 pub struct AnalysisState {
     pub context_stack: Vec<SemanticContext>,
     pub errors: Vec<SemanticError>,
+    pub warnings: Vec<SemanticWarning>,
+    pub type_table: TypeTable,
+    next_loop_id: u32,
+    /// Exit labels that a break is going to goto, so the loop needs to emit them.
+    used_loop_exit_labels: Vec<String>,
 }
 
 impl AnalysisState {
-    pub fn new() -> Self {
+    pub fn new(type_table: TypeTable) -> Self {
         let mut context_stack = Vec::new();
         context_stack.push(SemanticContext {
             symbol_table: SymbolTable::new(),
             scope: None,
+            loop_exit_label: None,
+            in_switch: false,
         });
 
         Self {
             context_stack,
             errors: Vec::new(),
+            warnings: Vec::new(),
+            type_table,
+            next_loop_id: 0,
+            used_loop_exit_labels: Vec::new(),
         }
     }
 }
@@ -40,14 +67,52 @@ impl AnalysisState {
 pub fn analyze_statements(
     statements: &mut [Statement],
     function_table: &FunctionTable,
-) -> Vec<SemanticError> {
-    let mut state = AnalysisState::new();
+    type_table: &TypeTable,
+) -> (Vec<SemanticError>, Vec<SemanticWarning>) {
+    let mut state = AnalysisState::new(type_table.clone());
+
+    // Constants are visible everywhere (including in functions declared above
+    // them), so they're analyzed before anything else.
+    for statement in statements.iter_mut() {
+        if let Statement::VariableDeclaration(var_decl) = statement
+            && var_decl.is_const
+        {
+            state = analyze_variable_declaration(var_decl, state, function_table);
+        }
+    }
 
-    for statement in statements {
-        state = analyze_statement(statement, state, function_table);
+    state
+        .errors
+        .extend(get_duplicate_function_errors(statements, function_table));
+
+    for statement in statements.iter_mut() {
+        if !is_const_declaration(statement) {
+            state = analyze_statement(statement, state, function_table);
+        }
     }
 
-    state.errors
+    // Built after everything's resolved, so record constructions aren't
+    // mistaken for calls.
+    let call_graph = CallGraph::new(statements, function_table);
+    for (name, line) in call_graph.get_uncalled_functions() {
+        // Already an error (a call to it goes to the builtin), see
+        // analyze_function_declaration.
+        if is_builtin_function(name) {
+            continue;
+        }
+        state.warnings.push(SemanticWarning::FunctionNeverCalled {
+            name: name.to_string(),
+            line,
+        });
+    }
+
+    state.errors.extend(get_initialization_order_errors(
+        statements,
+        function_table,
+        &call_graph,
+    ));
+
+    (state.errors, state.warnings)
 }
 
 fn analyze_statement(
@@ -57,12 +122,27 @@ fn analyze_statement(
 ) -> AnalysisState {
     let mut state = state;
     match statement {
+        // Top level constants never get here (see analyze_statements).
+        Statement::VariableDeclaration(var_decl) if var_decl.is_const => {
+            state.errors.push(SemanticError::ConstNotGlobal {
+                name: var_decl.symbol_name.clone(),
+                line: var_decl.line_declared_on,
+            });
+        }
         Statement::VariableDeclaration(var_decl) => {
             state = analyze_variable_declaration(var_decl, state, function_table);
         }
         Statement::VariableAssignment(var_ass) => {
             state = analyze_variable_assignment(var_ass, state, function_table);
         }
+        // Functions are only declared at the top level; anything deeper is
+        // rejected instead of analyzed, since c can't nest them either.
+        Statement::FunctionDeclaration(func_decl) if state.context_stack.len() > 1 => {
+            state.errors.push(SemanticError::FunctionNotTopLevel {
+                name: func_decl.function_name.clone(),
+                line: func_decl.line_declared_on,
+            });
+        }
         Statement::FunctionDeclaration(func_decl) => {
             state = analyze_function_declaration(func_decl, state, function_table);
         }
@@ -78,9 +158,41 @@ fn analyze_statement(
         Statement::While(while_stmt) => {
             state = analyze_while_stmt(while_stmt, state, function_table);
         }
+        Statement::For(for_stmt) => {
+            state = analyze_for_stmt(for_stmt, state, function_table);
+        }
+        Statement::Break(break_stmt) => {
+            state = analyze_break_stmt(break_stmt, state);
+        }
+        Statement::Continue(continue_stmt) => {
+            state = analyze_loop_control_stmt("continue", continue_stmt.line, state);
+        }
+        Statement::Match(match_stmt) => {
+            state = analyze_match_stmt(match_stmt, state, function_table);
+        }
         Statement::RawFunctionCall(func_stmt) => {
             state = analyze_raw_func_call(func_stmt, state, function_table);
         }
+        // Every record and enum is a type for the whole file (see
+        // gather_declarations), so one inside of a block would leak out of it.
+        Statement::RecordDeclaration(record_decl) if state.context_stack.len() > 1 => {
+            state.errors.push(SemanticError::RecordNotTopLevel {
+                name: record_decl.record_name.clone(),
+                line: record_decl.line_declared_on,
+            });
+        }
+        Statement::RecordDeclaration(record_decl) => {
+            state = analyze_record_declaration(record_decl, state, function_table);
+        }
+        Statement::EnumDeclaration(enum_decl) if state.context_stack.len() > 1 => {
+            state.errors.push(SemanticError::EnumNotTopLevel {
+                name: enum_decl.enum_name.clone(),
+                line: enum_decl.line_declared_on,
+            });
+        }
+        Statement::EnumDeclaration(enum_decl) => {
+            state = analyze_enum_declaration(enum_decl, state);
+        }
         _ => (),
     }
     state
@@ -96,31 +208,54 @@ fn analyze_variable_assignment(
     // I can't think of anything else rn so just do these
     let mut line_number = 0;
 
+    let symbol_table = &state.context_stack.last().unwrap().symbol_table;
     resolve_logical_values(
         &mut var_ass.assigned_logical,
         function_table,
-        &state.context_stack.last().unwrap().symbol_table,
+        &state.type_table,
+        symbol_table,
     );
 
     // TODO: put this shit into its own function, its rlly large rn
-    let var_op = state
-        .context_stack
-        .last()
-        .unwrap()
-        .symbol_table
-        .get(&var_ass.var_name);
-
-    match var_op {
+    match symbol_table.get(&var_ass.var_name) {
+        Some(var_def) if var_def.is_const => {
+            state.errors.push(SemanticError::ConstAssigned {
+                name: var_ass.var_name.clone(),
+                first_line: var_def.line_declared_on,
+                line: var_ass.line_number,
+            });
+            return state;
+        }
         Some(var_def) => {
             // type check logical type with var being declared
             line_number = var_def.line_declared_on;
-            let declared_var_type = &var_def.data_type;
+            var_ass.is_global = var_def.is_global;
+
+            // xs[i].x <= ...; the type being assigned to is whatever's at the
+            // end of the accessors (see type resolution of Access values).
+            let declared_var_type = resolve_accessors(
+                var_def.data_type.clone(),
+                &mut var_ass.accessors,
+                function_table,
+                &state.type_table,
+                symbol_table,
+            );
+
             let assigned_logical_type = &var_ass.assigned_logical.data_type;
-            if assigned_logical_type != declared_var_type {
+            if let Some(error) = get_accessor_errors(
+                &var_ass.var_name,
+                &var_ass.accessors,
+                function_table,
+                &state.type_table,
+                var_ass.line_number,
+            ) {
+                state.errors.push(error);
+                return state;
+            } else if *assigned_logical_type != declared_var_type {
                 state.errors.push(SemanticError::TypeMismatch {
-                    expected: declared_var_type.clone(),
+                    expected: declared_var_type,
                     found: assigned_logical_type.clone(),
-                    line: var_def.line_declared_on,
+                    line: line_number,
                 });
             }
         }
@@ -133,7 +268,12 @@ fn analyze_variable_assignment(
         }
     }
 
-    let logical_err = validate_logical(&var_ass.assigned_logical, line_number);
+    let logical_err = validate_logical(
+        &var_ass.assigned_logical,
+        function_table,
+        &state.type_table,
+        line_number,
+    );
     if logical_err.len() > 0 {
         // if there's a problem with the logical being assigned to the var,
         // we can't add it to our map.
@@ -153,13 +293,40 @@ fn analyze_variable_declaration(
 ) -> AnalysisState {
     let mut state = state;
 
+    if let Some(error) = get_undeclared_type_error(
+        &var_decl.data_type,
+        &state.type_table,
+        var_decl.line_declared_on,
+    ) {
+        state.errors.push(error);
+        return state;
+    }
+
+    // Constants are c static consts, so their value has to be known when compiling.
+    // Checked before anything else, since constants are analyzed before the
+    // variables they might be trying to use are declared.
+    if var_decl.is_const && !is_compile_time_constant(&var_decl.assigned_logical, &state.type_table)
+    {
+        state.errors.push(SemanticError::ConstNotConstant {
+            name: var_decl.symbol_name.clone(),
+            line: var_decl.line_declared_on,
+        });
+        return state;
+    }
+
     resolve_logical_values(
         &mut var_decl.assigned_logical,
         function_table,
+        &state.type_table,
         &state.context_stack.last().unwrap().symbol_table,
     );
 
-    let logical_err = validate_logical(&var_decl.assigned_logical, var_decl.line_declared_on);
+    let logical_err = validate_logical(
+        &var_decl.assigned_logical,
+        function_table,
+        &state.type_table,
+        var_decl.line_declared_on,
+    );
     if logical_err.len() > 0 {
         // if there's a problem with the logical being assigned to the var,
         // we can't add it to our map.
@@ -169,6 +336,20 @@ fn analyze_variable_declaration(
         return state;
     }
 
+    // let x: 5; takes the type of its value.
+    if var_decl.data_type == DataType::Unknown {
+        match &var_decl.assigned_logical.data_type {
+            DataType::Void | DataType::Unknown | DataType::Invalid => {
+                state.errors.push(SemanticError::TypeNotInferred {
+                    name: var_decl.symbol_name.clone(),
+                    line: var_decl.line_declared_on,
+                });
+                return state;
+            }
+            inferred_type => var_decl.data_type = inferred_type.clone(),
+        }
+    }
+
     // type check logical type with var being declared
     let declared_var_type = &var_decl.data_type;
     let assigned_logical_type = &var_decl.assigned_logical.data_type;
@@ -184,6 +365,7 @@ fn analyze_variable_declaration(
         &var_decl.symbol_name,
         &var_decl.data_type,
         var_decl.line_declared_on,
+        var_decl.is_const,
         &mut state,
     ) {
         state.errors.push(error);
@@ -212,7 +394,77 @@ fn analyze_function_declaration(
     function_table: &FunctionTable,
 ) -> AnalysisState {
     let mut state = state;
-    state = push_scope_for_function(&func_decl.function_name, state, function_table);
+
+    if is_builtin_function(&func_decl.function_name) {
+        state.errors.push(SemanticError::FunctionNameReserved {
+            name: func_decl.function_name.clone(),
+            line: func_decl.line_declared_on,
+        });
+        return state;
+    }
+
+    let parameter_types = func_decl.parameter_types();
+    if let Some(function_def) =
+        function_table.get_func_def(&func_decl.function_name, &parameter_types)
+    {
+        // A redeclaration was already reported (see get_duplicate_function_errors).
+        // The table only has the first declaration, so the body can't be checked
+        // against it (ex. it might return something else).
+        if function_def.line_declared_on != func_decl.line_declared_on {
+            return state;
+        }
+
+        let param_types = function_def.parameters.iter().map(|param| &param.data_type);
+        for data_type in param_types.chain([&function_def.return_type]) {
+            if let Some(error) =
+                get_undeclared_type_error(data_type, &state.type_table, func_decl.line_declared_on)
+            {
+                state.errors.push(error);
+            }
+        }
+    }
+
+    // Defaults are filled in at every call that leaves them out, so they're
+    // checked once, here.
+    for param in &mut func_decl.parameters {
+        let Some(default) = &mut param.default else {
+            continue;
+        };
+        let line = func_decl.line_declared_on;
+
+        if !is_compile_time_constant(default, &state.type_table) {
+            state.errors.push(SemanticError::DefaultNotConstant {
+                function: func_decl.function_name.clone(),
+                parameter: param.name.clone(),
+                line,
+            });
+            continue;
+        }
+
+        resolve_logical_values(
+            default,
+            function_table,
+            &state.type_table,
+            &SymbolTable::new(),
+        );
+        let default_errors = validate_logical(default, function_table, &state.type_table, line);
+        if !default_errors.is_empty() {
+            state.errors.extend(default_errors);
+            continue;
+        }
+
+        if default.data_type != param.data_type {
+            state.errors.push(SemanticError::DefaultTypeMismatch {
+                function: func_decl.function_name.clone(),
+                parameter: param.name.clone(),
+                expected: param.data_type.clone(),
+                found: default.data_type.clone(),
+                line,
+            });
+        }
+    }
+
+    state = push_scope_for_function(func_decl, state, function_table);
 
     // Only check return type requirement.
     // Type checking of the return is done in analyze_return function
@@ -245,17 +497,53 @@ fn analyze_print_statement(
 ) -> AnalysisState {
     let current_symbol_table = &state.context_stack.last().unwrap().symbol_table;
 
-    resolve_logical_values(
-        &mut print_stmt.logical,
-        function_table,
-        current_symbol_table,
-    );
+    for logical in print_stmt
+        .logicals
+        .iter_mut()
+        .chain(&mut print_stmt.separator)
+    {
+        resolve_logical_values(
+            logical,
+            function_table,
+            &state.type_table,
+            current_symbol_table,
+        );
+
+        let logical_err =
+            validate_logical(logical, function_table, &state.type_table, print_stmt.line);
+        if !logical_err.is_empty() {
+            state.errors.extend(logical_err);
+        } else if logical.data_type == DataType::Invalid {
+            // TODO: improve this error message
+            state.errors.push(SemanticError::ExpressionInvalid {
+                line: print_stmt.line,
+            })
+        }
+    }
 
-    if print_stmt.logical.data_type == DataType::Invalid {
-        // TODO: improve this error message
-        state.errors.push(SemanticError::ExpressionInvalid {
+    if let Some(position) = print_stmt.positional_after_named {
+        let function = match (print_stmt.to_stderr, print_stmt.is_print_ln) {
+            (false, false) => "print",
+            (false, true) => "println",
+            (true, false) => "eprint",
+            (true, true) => "eprintln",
+        };
+        state
+            .errors
+            .push(SemanticError::PositionalArgumentAfterNamed {
+                function: function.to_string(),
+                position,
+                line: print_stmt.line,
+            });
+    }
+
+    if let Some(separator) = &print_stmt.separator
+        && !matches!(separator.data_type, DataType::String | DataType::Invalid)
+    {
+        state.errors.push(SemanticError::PrintSeparatorInvalid {
+            found: separator.data_type.clone(),
             line: print_stmt.line,
-        })
+        });
     }
 
     state
@@ -270,9 +558,21 @@ fn analyze_return_stmt(
     resolve_logical_values(
         &mut return_stmt.return_value,
         function_table,
+        &state.type_table,
         &state.context_stack.last().unwrap().symbol_table, // TODO: make a helper function for this LOL
     );
 
+    let logical_err = validate_logical(
+        &return_stmt.return_value,
+        function_table,
+        &state.type_table,
+        return_stmt.line_declared_on,
+    );
+    if !logical_err.is_empty() {
+        state.errors.extend(logical_err);
+        return state;
+    }
+
     state = ensure_return_type_matches_function(state, function_table, return_stmt);
 
     state
@@ -283,30 +583,37 @@ fn analyze_if_stmt(
     mut state: AnalysisState,
     function_table: &FunctionTable,
 ) -> AnalysisState {
-    // need to go through the logical of the if statement, and resolve all expressions.
-    // We still need to ensure that the types are legit
-    let symbol_table = &state.context_stack.last().unwrap().symbol_table; // TODO: make a helper function for this LOL
-    resolve_logical_values(&mut stmt.condition, function_table, symbol_table);
-
-    let logical_err = validate_logical(&stmt.condition, stmt.line_declared_on);
-    if logical_err.len() > 0 {
-        // if there's a problem with the logical being assigned to the var,
-        // we can't add it to our map.
-        // Also prevents duplicate errors for the same statement.
-        // Return early.
-        state.errors.extend(logical_err);
-        return state;
-    }
-
-    // TODO: need to check all the statements inside of the if stmt, right?
-    // TODO: also don't we need to alter the scope stack to account for the new
-    // block of code??
+    // Each arm (the if and all of the elseIfs) gets its own scope.
+    for arm in stmt.arms.iter_mut() {
+        // need to go through the logical of the arm, and resolve all expressions.
+        // We still need to ensure that the types are legit
+        let symbol_table = &state.context_stack.last().unwrap().symbol_table; // TODO: make a helper function for this LOL
+        resolve_logical_values(
+            &mut arm.condition,
+            function_table,
+            &state.type_table,
+            symbol_table,
+        );
+
+        let logical_err = validate_logical(
+            &arm.condition,
+            function_table,
+            &state.type_table,
+            arm.line_declared_on,
+        );
+        if !logical_err.is_empty() {
+            // If the condition is broken, skip the arm's body.
+            // Prevents duplicate errors for the same statement.
+            state.errors.extend(logical_err);
+            continue;
+        }
 
-    push_scope_for_new_block(&mut state);
-    for statement in stmt.if_body.iter_mut() {
-        state = analyze_statement(statement, state, function_table);
+        push_scope_for_new_block(&mut state);
+        for statement in arm.body.iter_mut() {
+            state = analyze_statement(statement, state, function_table);
+        }
+        state = pop_scope(state);
     }
-    state = pop_scope(state);
 
     if let Some(else_statement_vec) = stmt.else_body.as_mut() {
         push_scope_for_new_block(&mut state);
@@ -314,11 +621,90 @@ fn analyze_if_stmt(
             state = analyze_statement(statement, state, function_table);
         }
         state = pop_scope(state);
+    } else if let Some((enum_name, handled_variants)) = get_enum_if_chain(stmt)
+        && let Some(enum_symbol) = state.type_table.get_enum(&enum_name)
+    {
+        let unhandled_variants: Vec<String> = enum_symbol
+            .variants
+            .iter()
+            .filter(|variant| !handled_variants.contains(variant))
+            .cloned()
+            .collect();
+
+        if !unhandled_variants.is_empty() {
+            state.warnings.push(SemanticWarning::UnhandledEnumVariants {
+                construct: "if chain",
+                enum_name,
+                variants: unhandled_variants,
+                line: stmt.arms[0].line_declared_on,
+            });
+        }
     }
 
     state
 }
 
+/// If every arm of the if statement is comparing the same variable against
+/// variants of an enum (if c == Color.Red ... elseIf c == Color.Green or
+/// c == Color.Blue ...), returns the enum and every variant that's handled.
+fn get_enum_if_chain(stmt: &IfStatement) -> Option<(String, Vec<String>)> {
+    let mut enum_name: Option<&str> = None;
+    let mut compared_name: Option<&str> = None;
+    let mut handled_variants = Vec::new();
+
+    for arm in &stmt.arms {
+        let condition = &arm.condition;
+        if condition
+            .operators
+            .iter()
+            .any(|operator| *operator != LogicalOperator::Or)
+        {
+            return None;
+        }
+
+        for comparison in &condition.comparisons {
+            if !matches!(
+                comparison.operators.as_slice(),
+                [ComparisonOperator::equalequal]
+            ) {
+                return None;
+            }
+            let left = comparison.expressions[0].as_single_value()?;
+            let right = comparison.expressions.get(1)?.as_single_value()?;
+
+            // The variant can be on either side.
+            let (variant_value, other_value) = if left.value_type == ValueType::EnumVariant {
+                (left, right)
+            } else {
+                (right, left)
+            };
+            let (ValueType::EnumVariant, ValueType::Variable) =
+                (&variant_value.value_type, &other_value.value_type)
+            else {
+                return None;
+            };
+            let [
+                Accessor {
+                    kind: AccessorKind::Field(variant),
+                    ..
+                },
+            ] = variant_value.accessors.as_slice()
+            else {
+                return None;
+            };
+
+            if *enum_name.get_or_insert(&variant_value.raw_text) != variant_value.raw_text
+                || *compared_name.get_or_insert(&other_value.raw_text) != other_value.raw_text
+            {
+                return None;
+            }
+            handled_variants.push(variant.clone());
+        }
+    }
+
+    Some((enum_name?.to_string(), handled_variants))
+}
+
 /// RN this is pretty identical to if stmt, but don't feel like taking the effort
 /// to make generic. Its whatever
 fn analyze_while_stmt(
@@ -329,9 +715,19 @@ fn analyze_while_stmt(
     // need to go through the logical of the if statement, and resolve all expressions.
     // We still need to ensure that the types are legit
     let symbol_table = &state.context_stack.last().unwrap().symbol_table; // TODO: make a helper function for this LOL
-    resolve_logical_values(&mut stmt.condition, function_table, symbol_table);
+    resolve_logical_values(
+        &mut stmt.condition,
+        function_table,
+        &state.type_table,
+        symbol_table,
+    );
 
-    let logical_err = validate_logical(&stmt.condition, stmt.line_declared_on);
+    let logical_err = validate_logical(
+        &stmt.condition,
+        function_table,
+        &state.type_table,
+        stmt.line_declared_on,
+    );
     if logical_err.len() > 0 {
         // if there's a problem with the logical being assigned to the var,
         // we can't add it to our map.
@@ -341,15 +737,263 @@ fn analyze_while_stmt(
         return state;
     }
 
-    push_scope_for_new_block(&mut state);
+    let exit_label = push_scope_for_new_loop_body(&mut state);
     for statement in stmt.body.iter_mut() {
         state = analyze_statement(statement, state, function_table);
     }
     state = pop_scope(state);
 
+    if state.used_loop_exit_labels.contains(&exit_label) {
+        stmt.exit_label = Some(exit_label);
+    }
+
     state
 }
 
+fn analyze_for_stmt(
+    stmt: &mut ForStatement,
+    mut state: AnalysisState,
+    function_table: &FunctionTable,
+) -> AnalysisState {
+    // The range is evaluated outside of the loop's scope, so the loop variable
+    // can't be used in it.
+    let mut range_logicals: Vec<&mut Logical> = vec![&mut stmt.start, &mut stmt.end];
+    if let Some(step) = stmt.step.as_mut() {
+        range_logicals.push(step);
+    }
+
+    let mut range_is_valid = true;
+    for logical in range_logicals {
+        let symbol_table = &state.context_stack.last().unwrap().symbol_table;
+        resolve_logical_values(logical, function_table, &state.type_table, symbol_table);
+
+        let logical_err = validate_logical(
+            logical,
+            function_table,
+            &state.type_table,
+            stmt.line_declared_on,
+        );
+        if !logical_err.is_empty() {
+            state.errors.extend(logical_err);
+            range_is_valid = false;
+        } else if logical.data_type != DataType::Number {
+            state.errors.push(SemanticError::TypeMismatch {
+                expected: DataType::Number,
+                found: logical.data_type.clone(),
+                line: stmt.line_declared_on,
+            });
+            range_is_valid = false;
+        }
+    }
+
+    if !range_is_valid {
+        return state;
+    }
+
+    // The loop variable only exists inside of the body.
+    let exit_label = push_scope_for_new_loop_body(&mut state);
+    if let Err(error) = add_variable_to_current_scope(
+        &stmt.var_name,
+        &DataType::Number,
+        stmt.line_declared_on,
+        false,
+        &mut state,
+    ) {
+        state.errors.push(error);
+    }
+
+    for statement in stmt.body.iter_mut() {
+        state = analyze_statement(statement, state, function_table);
+    }
+    state = pop_scope(state);
+
+    if state.used_loop_exit_labels.contains(&exit_label) {
+        stmt.exit_label = Some(exit_label);
+    }
+
+    state
+}
+
+fn analyze_loop_control_stmt(keyword: &str, line: u32, mut state: AnalysisState) -> AnalysisState {
+    if state
+        .context_stack
+        .last()
+        .unwrap()
+        .loop_exit_label
+        .is_none()
+    {
+        state.errors.push(SemanticError::LoopControlOutsideLoop {
+            keyword: keyword.to_string(),
+            line,
+        });
+    }
+    state
+}
+
+fn analyze_break_stmt(stmt: &mut BreakStatement, state: AnalysisState) -> AnalysisState {
+    let mut state = analyze_loop_control_stmt("break", stmt.line, state);
+
+    // Inside of a switch, a c break wouldn't leave the loop; jump past it instead.
+    let current_context = state.context_stack.last().unwrap();
+    if current_context.in_switch
+        && let Some(exit_label) = current_context.loop_exit_label.clone()
+    {
+        stmt.exit_label = Some(exit_label.clone());
+        state.used_loop_exit_labels.push(exit_label);
+    }
+
+    state
+}
+
+fn analyze_match_stmt(
+    stmt: &mut MatchStatement,
+    mut state: AnalysisState,
+    function_table: &FunctionTable,
+) -> AnalysisState {
+    let symbol_table = &state.context_stack.last().unwrap().symbol_table;
+    resolve_logical_values(
+        &mut stmt.scrutinee,
+        function_table,
+        &state.type_table,
+        symbol_table,
+    );
+
+    let logical_err = validate_logical(
+        &stmt.scrutinee,
+        function_table,
+        &state.type_table,
+        stmt.line_declared_on,
+    );
+    if !logical_err.is_empty() {
+        state.errors.extend(logical_err);
+        return state;
+    }
+
+    let scrutinee_type = stmt.scrutinee.data_type.clone();
+    if !matches!(
+        scrutinee_type,
+        DataType::Number | DataType::String | DataType::Enum(_)
+    ) {
+        state.errors.push(SemanticError::MatchTypeInvalid {
+            found: scrutinee_type,
+            line: stmt.line_declared_on,
+        });
+        return state;
+    }
+
+    // Every case value must be a literal of the same type as the scrutinee.
+    // Keep track of the ones we've seen (and where) to warn about duplicates.
+    let mut seen_values: Vec<(Literal, u32)> = Vec::new();
+    for case in stmt.cases.iter_mut() {
+        for value in case.values.iter_mut() {
+            let symbol_table = &state.context_stack.last().unwrap().symbol_table;
+            resolve_logical_values(value, function_table, &state.type_table, symbol_table);
+
+            // ex. a variant the enum doesn't have
+            let value_err = validate_logical(
+                value,
+                function_table,
+                &state.type_table,
+                case.line_declared_on,
+            );
+            if !value_err.is_empty() {
+                state.errors.extend(value_err);
+                continue;
+            }
+
+            let Some(literal) = value.as_literal() else {
+                state.errors.push(SemanticError::MatchCaseNotLiteral {
+                    line: case.line_declared_on,
+                });
+                continue;
+            };
+
+            if value.data_type != scrutinee_type {
+                state.errors.push(SemanticError::TypeMismatch {
+                    expected: scrutinee_type.clone(),
+                    found: value.data_type.clone(),
+                    line: case.line_declared_on,
+                });
+                continue;
+            }
+
+            if let Some((_, first_line)) = seen_values.iter().find(|(seen, _)| *seen == literal) {
+                state.warnings.push(SemanticWarning::DuplicateMatchCase {
+                    value: literal_to_str(&literal),
+                    line: case.line_declared_on,
+                    first_line: *first_line,
+                });
+            } else {
+                seen_values.push((literal, case.line_declared_on));
+            }
+        }
+    }
+
+    // Number and enum matches become a c switch.
+    let is_switch = matches!(scrutinee_type, DataType::Number | DataType::Enum(_));
+    for case in stmt.cases.iter_mut() {
+        push_scope_for_new_match_case(&mut state, is_switch);
+        for statement in case.body.iter_mut() {
+            state = analyze_statement(statement, state, function_table);
+        }
+        state = pop_scope(state);
+    }
+
+    match stmt.default_body.as_mut() {
+        Some(default_body) => {
+            push_scope_for_new_match_case(&mut state, is_switch);
+            for statement in default_body.iter_mut() {
+                state = analyze_statement(statement, state, function_table);
+            }
+            state = pop_scope(state);
+        }
+        // Without a default, a match over an enum only needs to handle every variant.
+        None => match &scrutinee_type {
+            DataType::Enum(enum_name) => {
+                let unhandled_variants: Vec<String> = state
+                    .type_table
+                    .get_enum(enum_name)
+                    .map(|enum_symbol| enum_symbol.variants.as_slice())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|variant| {
+                        !seen_values.iter().any(|(seen, _)| {
+                            matches!(seen, Literal::EnumVariant { variant: handled, .. } if handled == *variant)
+                        })
+                    })
+                    .cloned()
+                    .collect();
+
+                if !unhandled_variants.is_empty() {
+                    state.warnings.push(SemanticWarning::UnhandledEnumVariants {
+                        construct: "match",
+                        enum_name: enum_name.clone(),
+                        variants: unhandled_variants,
+                        line: stmt.line_declared_on,
+                    });
+                }
+            }
+            _ => {
+                state.warnings.push(SemanticWarning::MatchMissingDefault {
+                    line: stmt.line_declared_on,
+                });
+            }
+        },
+    }
+
+    state
+}
+
+/// How a literal is written in plank, for messages.
+fn literal_to_str(literal: &Literal) -> String {
+    match literal {
+        Literal::Number(number) => number.to_string(),
+        Literal::String(string) => format!("\"{}\"", string),
+        Literal::Boolean(boolean) => boolean.to_string(),
+        Literal::EnumVariant { enum_name, variant } => format!("{}.{}", enum_name, variant),
+    }
+}
+
 fn analyze_raw_func_call(
     stmt: &mut RawFunctionCallStatement,
     mut state: AnalysisState,
@@ -359,47 +1003,157 @@ fn analyze_raw_func_call(
     resolve_value(
         &mut stmt.value,
         function_table,
+        &state.type_table,
         &state.context_stack.last().unwrap().symbol_table,
     );
 
-    //state = analyze_function_call(&stmt.value, stmt.line, state, function_table);
-    match function_table.get_func_def_using_str(&stmt.value.raw_text) {
-        None => {
-            // This function doesn't exist. Add error.
-            state.errors.push(SemanticError::FunctionNotDeclared {
-                name: stmt.value.raw_text.clone(),
-                line: stmt.line,
-            })
+    // Checks which overload the call goes to, and its arguments (builtins and
+    // record constructions are checked in there too).
+    state.errors.extend(validate_value(
+        &stmt.value,
+        function_table,
+        &state.type_table,
+        stmt.line,
+    ));
+
+    state
+}
+
+fn analyze_record_declaration(
+    stmt: &RecordDeclarationStatement,
+    mut state: AnalysisState,
+    function_table: &FunctionTable,
+) -> AnalysisState {
+    let name = &stmt.record_name;
+    let line = stmt.line_declared_on;
+
+    if let Err(error) = ensure_type_declared_once(name, line, &state.type_table) {
+        state.errors.push(error);
+        return state;
+    }
+
+    if is_builtin_function(name) || function_table.contains_name(name) {
+        state.errors.push(SemanticError::RecordNameConflict {
+            name: name.clone(),
+            line,
+        });
+        return state;
+    }
+
+    if stmt.fields.is_empty() {
+        state.errors.push(SemanticError::RecordHasNoFields {
+            name: name.clone(),
+            line,
+        });
+        return state;
+    }
+
+    for field in &stmt.fields {
+        if let Some(error) = get_undeclared_type_error(&field.data_type, &state.type_table, line) {
+            state.errors.push(error);
+            return state;
         }
-        Some(function_definition) => {
-            // the types of all the parameters should be resolved by now.
-            // Loop through definition, and confirm that it matches the call.
-            for (expected_param_idx, expected_param) in
-                function_definition.parameters.iter().enumerate()
-            {
-                if let Some(function_call_param) = stmt.value.params.get(expected_param_idx) {
-                    if function_call_param.data_type != expected_param.data_type {
-                        state.errors.push(SemanticError::UnexpectedStatement {
-                            line: stmt.line,
-                            explanation: format!(
-                                "Provided arguments to function {} are invalid.",
-                                stmt.value
-                            ),
-                        })
-                    }
-                } else {
-                    // the parameter wasn't found, also add error.
-                    state.errors.push(SemanticError::IncorrectParameters {
-                        parameters_expected: function_definition.parameters.len(),
-                        parameters_provided: stmt.value.params.len(),
-                        line: stmt.line,
-                    });
+    }
+
+    if record_contains(name, name, &state.type_table, &mut Vec::new()) {
+        state.errors.push(SemanticError::RecordContainsItself {
+            name: name.clone(),
+            line,
+        });
+    }
+
+    state
+}
+
+fn analyze_enum_declaration(
+    stmt: &EnumDeclarationStatement,
+    mut state: AnalysisState,
+) -> AnalysisState {
+    if let Err(error) =
+        ensure_type_declared_once(&stmt.enum_name, stmt.line_declared_on, &state.type_table)
+    {
+        state.errors.push(error);
+        return state;
+    }
+
+    for (idx, variant) in stmt.variants.iter().enumerate() {
+        if stmt.variants[..idx].contains(variant) {
+            state
+                .errors
+                .push(SemanticError::EnumVariantAlreadyDeclared {
+                    enum_name: stmt.enum_name.clone(),
+                    variant: variant.clone(),
+                    line: stmt.line_declared_on,
+                });
+        }
+    }
+
+    state
+}
+
+/// The type table only keeps the first record or enum declared with a name,
+/// so any other declaration of it is a redeclaration.
+fn ensure_type_declared_once(
+    name: &str,
+    line: u32,
+    type_table: &TypeTable,
+) -> Result<(), SemanticError> {
+    match type_table.get_line_declared_on(name) {
+        Some(first_line) if first_line != line => Err(SemanticError::TypeAlreadyDeclared {
+            name: name.to_string(),
+            first_line,
+            redeclaration_line: line,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Whether a record holds target_name by value, either directly or through the
+/// records in its fields. Lists don't count, since they can be empty.
+fn record_contains(
+    record_name: &str,
+    target_name: &str,
+    type_table: &TypeTable,
+    visited: &mut Vec<String>,
+) -> bool {
+    let Some(record) = type_table.get_record(record_name) else {
+        return false;
+    };
+
+    for field in &record.fields {
+        if let DataType::Record(field_record_name) = &field.data_type {
+            if field_record_name == target_name {
+                return true;
+            }
+            if !visited.contains(field_record_name) {
+                visited.push(field_record_name.clone());
+                if record_contains(field_record_name, target_name, type_table, visited) {
+                    return true;
                 }
             }
         }
     }
 
-    state
+    false
+}
+
+/// Record types are only known by name when they're parsed, so make sure the
+/// record was actually declared somewhere (including inside of Lists).
+fn get_undeclared_type_error(
+    data_type: &DataType,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
+    match data_type {
+        DataType::Record(name) if !type_table.contains_record(name) => {
+            Some(SemanticError::TypeNotDeclared {
+                name: name.clone(),
+                line,
+            })
+        }
+        DataType::List(element_type) => get_undeclared_type_error(element_type, type_table, line),
+        _ => None,
+    }
 }
 
 fn ensure_return_type_matches_function(
@@ -407,8 +1161,20 @@ fn ensure_return_type_matches_function(
     function_table: &FunctionTable,
     return_stmt: &ReturnStatement,
 ) -> AnalysisState {
-    let current_analysis_context = state.context_stack.last().unwrap();
-    let current_function_context = current_analysis_context.scope.unwrap();
+    // Blocks don't have a scope of their own, so look out to the function
+    // they're in.
+    let Some(current_function_context) = state
+        .context_stack
+        .iter()
+        .rev()
+        .find_map(|context| context.scope)
+    else {
+        state.errors.push(SemanticError::UnexpectedStatement {
+            line: return_stmt.line_declared_on,
+            explanation: "return can only be used inside of a function".to_string(),
+        });
+        return state;
+    };
     let current_function = function_table.get_using_id(current_function_context);
 
     if let Some(current_function) = current_function {
@@ -435,18 +1201,81 @@ fn ensure_return_type_matches_function(
     state
 }
 
+/// The first pass keeps the first of two functions with the same name and
+/// parameter types, so the ones after it are reported here.
+fn get_duplicate_function_errors(
+    statements: &[Statement],
+    function_table: &FunctionTable,
+) -> Vec<SemanticError> {
+    let mut errors = Vec::new();
+    let mut declared: Vec<&FunctionDeclarationStatement> = Vec::new();
+
+    for statement in statements {
+        let Statement::FunctionDeclaration(func_decl) = statement else {
+            continue;
+        };
+
+        let first_declaration = declared.iter().find(|other| {
+            other.function_name == func_decl.function_name
+                && other.parameter_types() == func_decl.parameter_types()
+        });
+        match first_declaration {
+            Some(first_declaration) => {
+                let signature = function_table
+                    .get_func_def(&func_decl.function_name, &func_decl.parameter_types())
+                    .map(|def| def.to_plank_str())
+                    .unwrap_or_else(|| func_decl.function_name.clone());
+                errors.push(SemanticError::FunctionAlreadyDeclared {
+                    signature,
+                    first_line: first_declaration.line_declared_on,
+                    redeclaration_line: func_decl.line_declared_on,
+                });
+            }
+            None => declared.push(func_decl),
+        }
+    }
+
+    errors
+}
+
+fn is_const_declaration(statement: &Statement) -> bool {
+    matches!(statement, Statement::VariableDeclaration(var_decl) if var_decl.is_const)
+}
+
+/// Literals (ex. 10, -3, "hi", true) and enum variants.
+/// Called before type resolution, so an enum variant is still an Access.
+fn is_compile_time_constant(logical: &Logical, type_table: &TypeTable) -> bool {
+    if logical.as_literal().is_some() {
+        return true;
+    }
+
+    match logical.comparisons.as_slice() {
+        [comparison] => match comparison.expressions.as_slice() {
+            [expression] => expression.as_single_value().is_some_and(|value| {
+                value.value_type == ValueType::Access && type_table.contains_enum(&value.raw_text)
+            }),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 /// TODO; move into module specific to analysis state functions
 fn push_scope_for_function(
-    function_name: &str,
+    func_decl: &FunctionDeclarationStatement,
     state: AnalysisState,
     function_table: &FunctionTable,
 ) -> AnalysisState {
     let mut state = state;
-    if let Some(function_id) = function_table.get_id_with_function_name(function_name) {
-        if let Some(function_def) =
-            function_table.get_func_def_using_str(&function_name.to_string())
-        {
-            let mut new_symbol_table = SymbolTable::new();
+    let parameter_types = func_decl.parameter_types();
+    if let Some(function_id) = function_table.get_id(&func_decl.function_name, &parameter_types) {
+        if let Some(function_def) = function_table.get_using_id(function_id) {
+            // Constants, and the globals declared above the function so far,
+            // are looked up through the global scope (a parameter or variable
+            // with the same name hides them).
+            let global_symbol_table =
+                std::mem::take(&mut state.context_stack.last_mut().unwrap().symbol_table);
+            let mut new_symbol_table = SymbolTable::new_child(global_symbol_table, true);
 
             // Add function parameters to the new scope
             for parameter in &function_def.parameters {
@@ -454,12 +1283,18 @@ fn push_scope_for_function(
                     &parameter.name,
                     &parameter.data_type,
                     &function_def.line_declared_on,
+                    false,
+                    false,
                 );
             }
 
+            // A loop around the function declaration doesn't make the
+            // function body part of the loop.
             let new_context = SemanticContext {
                 symbol_table: new_symbol_table,
                 scope: Some(function_id),
+                loop_exit_label: None,
+                in_switch: false,
             };
 
             state.context_stack.push(new_context);
@@ -470,26 +1305,47 @@ fn push_scope_for_function(
 
 /// aka if/while stmts
 fn push_scope_for_new_block(state: &mut AnalysisState) {
-    // let mut new_symbol_table = state.context_stack.last().unwrap().symbol_table.clone();
-    let mut new_symbol_table = SymbolTable::new();
-
-    // lazy clone
-    for (key, value) in state.context_stack.last().unwrap().symbol_table.iter() {
-        new_symbol_table.insert(&value.identifier, &value.data_type, &value.line_declared_on);
-    }
-
+    let parent_context = state.context_stack.last_mut().unwrap();
+    let parent_symbol_table = std::mem::take(&mut parent_context.symbol_table);
     let new_context = SemanticContext {
-        symbol_table: new_symbol_table,
-        scope: None, // will this break anything?
+        symbol_table: SymbolTable::new_child(parent_symbol_table, false),
+        scope: None, // only a function's own context has one, see ensure_return_type_matches_function
+        loop_exit_label: parent_context.loop_exit_label.clone(),
+        in_switch: parent_context.in_switch,
     };
     state.context_stack.push(new_context);
 }
 
+/// Same as a normal block, but break/continue are allowed inside of it.
+/// Returns the label that goes after this loop.
+fn push_scope_for_new_loop_body(state: &mut AnalysisState) -> String {
+    let exit_label = format!("plank_loop_exit_{}", state.next_loop_id);
+    state.next_loop_id += 1;
+
+    push_scope_for_new_block(state);
+    let current_context = state.context_stack.last_mut().unwrap();
+    current_context.loop_exit_label = Some(exit_label.clone());
+    current_context.in_switch = false;
+
+    exit_label
+}
+
+fn push_scope_for_new_match_case(state: &mut AnalysisState, is_switch: bool) {
+    push_scope_for_new_block(state);
+    if is_switch {
+        state.context_stack.last_mut().unwrap().in_switch = true;
+    }
+}
+
 /// TODO; move into module specific to analysis state functions
 fn pop_scope(state: AnalysisState) -> AnalysisState {
     let mut state = state;
     if state.context_stack.len() > 1 {
-        state.context_stack.pop();
+        // The parent's table was moved into this one when it was pushed.
+        let context = state.context_stack.pop().unwrap();
+        if let Some(parent_symbol_table) = context.symbol_table.into_parent() {
+            state.context_stack.last_mut().unwrap().symbol_table = parent_symbol_table;
+        }
     }
     state
 }
@@ -499,24 +1355,26 @@ fn add_variable_to_current_scope(
     name: &str,
     data_type: &DataType,
     line: u32,
+    is_const: bool,
     state: &mut AnalysisState,
 ) -> Result<u8, SemanticError> {
+    // Anything declared at the top level of the file is a c global.
+    let is_global = state.context_stack.len() == 1;
     let current_context = state.context_stack.last_mut().unwrap();
 
-    if current_context.symbol_table.contains_name(name) {
-        if let Some(existing_var) = current_context.symbol_table.get(name) {
-            return Err(SemanticError::VariableAlreadyDeclared {
-                name: name.to_string(),
-                first_line: existing_var.line_declared_on,
-                redeclaration_line: line,
-            });
-        }
+    if let Some(existing_var) = current_context.symbol_table.get_in_current_function(name) {
+        return Err(SemanticError::VariableAlreadyDeclared {
+            name: name.to_string(),
+            first_line: existing_var.line_declared_on,
+            redeclaration_line: line,
+        });
     }
 
     let name_string = name.to_string();
-    if let Some(key) = current_context
-        .symbol_table
-        .insert(&name_string, data_type, &line)
+    if let Some(key) =
+        current_context
+            .symbol_table
+            .insert(&name_string, data_type, &line, is_const, is_global)
     {
         Ok(key)
     } else {
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::ast::{FunctionTable, Statement, ValueType, visit_statements};
+use crate::call_graph::CallGraph;
+use crate::semantic::SemanticError;
+
+/**
+ * Globals are the variables declared at the top level of the file (outside of
+ * any block). They live at c file scope, and every function declared below a
+ * global can read and assign to it.
+ * They're still set in order, as the top level statements run, so calling a
+ * function that (directly or through other functions) uses a global before
+ * the global's declaration has run is an error.
+ */
+pub fn get_initialization_order_errors(
+    statements: &[Statement],
+    function_table: &FunctionTable,
+    call_graph: &CallGraph,
+) -> Vec<SemanticError> {
+    let globals = get_globals(statements);
+
+    // Keyed by c name, like the call graph.
+    let mut globals_used: HashMap<String, Vec<&str>> = HashMap::new();
+    for statement in statements {
+        if let Statement::FunctionDeclaration(func_decl) = statement {
+            let c_name = function_table
+                .get_func_def(&func_decl.function_name, &func_decl.parameter_types())
+                .map(|def| function_table.get_c_name(def))
+                .unwrap_or_else(|| func_decl.function_name.clone());
+
+            // Constants are globals too, but they're set before anything runs.
+            let used = get_globals_used(&func_decl.body);
+            globals_used.insert(
+                c_name,
+                used.into_iter()
+                    .filter(|name| globals.contains_key(name))
+                    .collect(),
+            );
+        }
+    }
+
+    // Function bodies aren't visited here, only the statements that run as
+    // the file runs. A global is set once the top level statement declaring it
+    // has run (not by line, since several statements can be on one line).
+    let mut errors = Vec::new();
+    for (idx, top_level_statement) in statements.iter().enumerate() {
+        visit_statements(
+            std::slice::from_ref(top_level_statement),
+            &mut |statement| {
+                let line = statement.line();
+                let mut called = Vec::new();
+                statement.visit_values(&mut |value| {
+                    if value.value_type == ValueType::FunctionCall
+                        && let Some(c_name) = &value.function_c_name
+                    {
+                        called.push((value.raw_text.as_str(), c_name.as_str()));
+                    }
+                });
+
+                for (function_name, c_name) in called {
+                    let reachable = call_graph.get_reachable_functions(c_name);
+                    let uninitialized = reachable
+                        .iter()
+                        .flat_map(|func| globals_used.get(*func).into_iter().flatten())
+                        .find(|global| globals[**global].0 >= idx);
+
+                    if let Some(global) = uninitialized {
+                        errors.push(SemanticError::GlobalUsedBeforeInitialized {
+                            global: global.to_string(),
+                            global_line: globals[*global].1,
+                            function: function_name.to_string(),
+                            line,
+                        });
+                    }
+                }
+            },
+        );
+    }
+
+    errors
+}
+
+/// The name of every global, along with the index of the top level statement
+/// that declares it and the line it's on.
+/// Constants aren't included, since they're set before anything runs.
+fn get_globals(statements: &[Statement]) -> HashMap<&str, (usize, u32)> {
+    statements
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, statement)| match statement {
+            Statement::VariableDeclaration(var_decl) if !var_decl.is_const => Some((
+                var_decl.symbol_name.as_str(),
+                (idx, var_decl.line_declared_on),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Every global read or assigned to in a function body (the function's own
+/// variables can have the same name, see is_global on Value).
+fn get_globals_used(body: &[Statement]) -> Vec<&str> {
+    let mut variables = Vec::new();
+
+    visit_statements(body, &mut |statement| {
+        if let Statement::VariableAssignment(var_ass) = statement
+            && var_ass.is_global
+        {
+            variables.push(var_ass.var_name.as_str());
+        }
+        statement.visit_values(&mut |value| {
+            if matches!(value.value_type, ValueType::Variable | ValueType::Access)
+                && value.is_global
+            {
+                variables.push(value.raw_text.as_str());
+            }
+        });
+    });
+
+    variables
+}
@@ -6,6 +6,11 @@ pub use semantic_error::SemanticError;
 pub use semantic_error::print_failures_message;
 pub use semantic_error::print_success_message;
 
+mod semantic_warning;
+pub use semantic_warning::SemanticWarning;
+
+mod globals;
+
 mod type_resolution;
 
 mod validate;
@@ -1,4 +1,4 @@
-use crate::ast::{DataType, FunctionSymbol};
+use crate::ast::{DataType, FunctionSymbol, GeneralOperator};
 use colored::*;
 
 /// Represents different types of semantic errors
@@ -59,6 +59,241 @@ pub enum SemanticError {
         first_expr_datatype: DataType,
         got: DataType,
     },
+    /// break/continue used somewhere that isn't inside of a loop body.
+    LoopControlOutsideLoop {
+        keyword: String,
+        line: u32,
+    },
+    /// Only Numbers and Strings can be matched on.
+    MatchTypeInvalid {
+        found: DataType,
+        line: u32,
+    },
+    /// Case values have to be known at compile time.
+    MatchCaseNotLiteral {
+        line: u32,
+    },
+    /// Comparisons only take two operands; a < b < c isn't allowed.
+    ComparisonChained {
+        line: u32,
+    },
+    /// An operator was used on a type it doesn't support (ex. "hi" % 2)
+    OperatorTypeInvalid {
+        operator: GeneralOperator,
+        found: DataType,
+        line: u32,
+    },
+    /// [] doesn't say what type of List it is.
+    ListLiteralEmpty {
+        line: u32,
+    },
+    /// Every element of a list literal has to be the same type.
+    ListElementTypeMismatch {
+        expected: DataType,
+        found: DataType,
+        line: u32,
+    },
+    /// Indexed into something that isn't a List (or more times than it's nested).
+    IndexTargetInvalid {
+        name: String,
+        line: u32,
+    },
+    /// Accessed a field that the record doesn't have (or on something that isn't a record).
+    FieldNotFound {
+        name: String,
+        field: String,
+        line: u32,
+    },
+    /// A record was built with a value of the wrong type for one of its fields.
+    RecordFieldTypeMismatch {
+        record: String,
+        field: String,
+        expected: DataType,
+        found: DataType,
+        line: u32,
+    },
+    TypeAlreadyDeclared {
+        name: String,
+        first_line: u32,
+        redeclaration_line: u32,
+    },
+    /// Constants can't be assigned to after they're declared.
+    ConstAssigned {
+        name: String,
+        first_line: u32,
+        line: u32,
+    },
+    /// A constant's value has to be a literal (or enum variant).
+    ConstNotConstant {
+        name: String,
+        line: u32,
+    },
+    /// Constants can only be declared at the top level of the file.
+    ConstNotGlobal {
+        name: String,
+        line: u32,
+    },
+    /// Functions can only be declared at the top level of the file.
+    FunctionNotTopLevel {
+        name: String,
+        line: u32,
+    },
+    /// Records can only be declared at the top level of the file.
+    RecordNotTopLevel {
+        name: String,
+        line: u32,
+    },
+    /// Enums can only be declared at the top level of the file.
+    EnumNotTopLevel {
+        name: String,
+        line: u32,
+    },
+    /// A function was called before a global it uses (directly, or through
+    /// the functions it calls) was set.
+    GlobalUsedBeforeInitialized {
+        global: String,
+        global_line: u32,
+        function: String,
+        line: u32,
+    },
+    /// An enum was declared with the same variant twice.
+    EnumVariantAlreadyDeclared {
+        enum_name: String,
+        variant: String,
+        line: u32,
+    },
+    /// Color.Purple, when Color has no Purple.
+    EnumVariantNotFound {
+        enum_name: String,
+        variant: String,
+        line: u32,
+    },
+    /// Records are built like function calls, so they can't share a name with a function.
+    RecordNameConflict {
+        name: String,
+        line: u32,
+    },
+    RecordHasNoFields {
+        name: String,
+        line: u32,
+    },
+    /// A record can't hold itself (directly or through other records), it'd be infinitely big.
+    RecordContainsItself {
+        name: String,
+        line: u32,
+    },
+    /// A type was used that isn't a builtin type or a declared record.
+    TypeNotDeclared {
+        name: String,
+        line: u32,
+    },
+    /// A builtin function (ex. length) was given the wrong kind of argument.
+    BuiltinArgumentInvalid {
+        name: String,
+        expected: String,
+        found: DataType,
+        line: u32,
+    },
+    /// User functions can't reuse the name of a builtin function.
+    FunctionNameReserved {
+        name: String,
+        line: u32,
+    },
+    /// Overloads need different parameter types; signature is ex. area(Number, Number).
+    FunctionAlreadyDeclared {
+        signature: String,
+        first_line: u32,
+        redeclaration_line: u32,
+    },
+    /// An argument's type isn't the type of the parameter it's passed to.
+    /// position starts at 1.
+    ArgumentTypeMismatch {
+        function: String,
+        parameter: String,
+        position: usize,
+        expected: DataType,
+        found: DataType,
+        line: u32,
+    },
+    /// More than one overload could take the arguments of a call.
+    FunctionCallAmbiguous {
+        name: String,
+        candidates: Vec<String>,
+        /// Whether some candidate only matches by filling in defaults.
+        through_defaults: bool,
+        line: u32,
+    },
+    /// None of the overloads of an overloaded function take the arguments of a call.
+    NoMatchingOverload {
+        name: String,
+        found: Vec<DataType>,
+        overloads: Vec<String>,
+        line: u32,
+    },
+    /// Numbers are 64 bit signed integers; literals have to fit in one.
+    NumberLiteralOutOfRange {
+        literal: String,
+        line: u32,
+    },
+    /// A call didn't pass anything to a parameter without a default.
+    ArgumentMissing {
+        function: String,
+        parameter: String,
+        line: u32,
+    },
+    /// greet(nmae: "x"), when greet has no parameter called nmae.
+    ArgumentNameNotFound {
+        function: String,
+        name: String,
+        line: u32,
+    },
+    /// A parameter was passed more than one argument (by position and by name,
+    /// or by the same name twice).
+    ArgumentGivenTwice {
+        function: String,
+        parameter: String,
+        line: u32,
+    },
+    /// greet(name: "x", "yo"); once an argument is passed by name, the ones
+    /// after it have to be too.
+    PositionalArgumentAfterNamed {
+        function: String,
+        position: usize,
+        line: u32,
+    },
+    /// Defaults are filled in at every call, so they have to be known when compiling.
+    DefaultNotConstant {
+        function: String,
+        parameter: String,
+        line: u32,
+    },
+    DefaultTypeMismatch {
+        function: String,
+        parameter: String,
+        expected: DataType,
+        found: DataType,
+        line: u32,
+    },
+    /// "{xs}", when xs is a List (or anything else without a String form).
+    InterpolatedValueInvalid {
+        found: DataType,
+        line: u32,
+    },
+    /// Builtins only take their arguments by position.
+    BuiltinArgumentNamed {
+        name: String,
+        line: u32,
+    },
+    /// let x: doNothing(); has no type to give x.
+    TypeNotInferred {
+        name: String,
+        line: u32,
+    },
+    /// print(a, b, separator: 1);
+    PrintSeparatorInvalid {
+        found: DataType,
+        line: u32,
+    },
 }
 
 // Helper functions for formatting error messages
@@ -92,11 +327,11 @@ fn format_line(line: u32) -> ColoredString {
 }
 
 fn format_type(data_type: &DataType) -> ColoredString {
-    format!("{:?}", data_type).green().bold()
+    data_type.to_plank_str().green().bold()
 }
 
 fn format_type_error(data_type: &DataType) -> ColoredString {
-    format!("{:?}", data_type).red().bold()
+    data_type.to_plank_str().red().bold()
 }
 
 pub fn print_success_message() {
@@ -260,6 +495,571 @@ impl SemanticError {
                     got
                 );
             }
+            SemanticError::LoopControlOutsideLoop { keyword, line } => {
+                error_header("Loop control outside of a loop", *line);
+                eprintln!(
+                    "  {} '{}' can only be used inside of a while or for loop",
+                    error_line_start(),
+                    format_name(keyword)
+                );
+            }
+            SemanticError::MatchTypeInvalid { found, line } => {
+                error_header("Invalid match value", *line);
+                eprintln!(
+                    "  {} Can only match on a {}, a {} or an enum",
+                    error_line_start(),
+                    format_type(&DataType::Number),
+                    format_type(&DataType::String)
+                );
+                eprintln!(
+                    "  {} Found:    {}",
+                    error_line_end(),
+                    format_type_error(found)
+                );
+            }
+            SemanticError::MatchCaseNotLiteral { line } => {
+                error_header("Invalid case value", *line);
+                eprintln!(
+                    "  {} Case values must be literals or enum variants (ex. {}, {} or {})",
+                    error_line_start(),
+                    format_name("3"),
+                    format_name("\"hello\""),
+                    format_name("Color.Red")
+                );
+            }
+            SemanticError::ComparisonChained { line } => {
+                error_header("Comparisons can't be chained", *line);
+                eprintln!(
+                    "  {} A comparison can only compare two values (ex. {})",
+                    error_line_start(),
+                    format_name("a < b < c")
+                );
+                eprintln!(
+                    "  {} Combine them with 'and' instead: {}",
+                    error_line_end(),
+                    format_name("a < b and b < c")
+                );
+            }
+            SemanticError::OperatorTypeInvalid {
+                operator,
+                found,
+                line,
+            } => {
+                error_header("Operator used on invalid type", *line);
+                eprintln!(
+                    "  {} Operator {} can't be used on a {}",
+                    error_line_start(),
+                    format_name(operator.to_str()),
+                    format_type_error(found)
+                );
+            }
+            SemanticError::ListLiteralEmpty { line } => {
+                error_header("Empty list", *line);
+                eprintln!(
+                    "  {} The type of an empty list can't be figured out; give it at least one element",
+                    error_line_start()
+                );
+            }
+            SemanticError::ListElementTypeMismatch {
+                expected,
+                found,
+                line,
+            } => {
+                error_header("List elements have different types", *line);
+                eprintln!(
+                    "  {} Every element must be the same type as the first",
+                    error_line_start()
+                );
+                eprintln!(
+                    "  {} Expected: {}",
+                    error_line_middle(),
+                    format_type(expected)
+                );
+                eprintln!(
+                    "  {} Found:    {}",
+                    error_line_end(),
+                    format_type_error(found)
+                );
+            }
+            SemanticError::IndexTargetInvalid { name, line } => {
+                error_header("Invalid index", *line);
+                eprintln!(
+                    "  {} {} isn't a List, so it can't be indexed",
+                    error_line_start(),
+                    format_name(name)
+                );
+                eprintln!(
+                    "  {} Only Lists can be indexed, once for every level they're nested",
+                    error_line_end()
+                );
+            }
+            SemanticError::FieldNotFound { name, field, line } => {
+                error_header("Field not found", *line);
+                eprintln!(
+                    "  {} {} has no field named '{}'",
+                    error_line_start(),
+                    format_name(name),
+                    format_name(field)
+                );
+                eprintln!(
+                    "  {} Only records have fields, and only the ones they're declared with",
+                    error_line_end()
+                );
+            }
+            SemanticError::RecordFieldTypeMismatch {
+                record,
+                field,
+                expected,
+                found,
+                line,
+            } => {
+                error_header("Record field type mismatch", *line);
+                eprintln!(
+                    "  {} Field '{}' of record '{}' has the wrong type",
+                    error_line_start(),
+                    format_name(field),
+                    format_name(record)
+                );
+                eprintln!(
+                    "  {} Expected: {}",
+                    error_line_middle(),
+                    format_type(expected)
+                );
+                eprintln!(
+                    "  {} Found:    {}",
+                    error_line_end(),
+                    format_type_error(found)
+                );
+            }
+            SemanticError::TypeAlreadyDeclared {
+                name,
+                first_line,
+                redeclaration_line,
+            } => {
+                error_header("Type redeclaration", *redeclaration_line);
+                eprintln!(
+                    "  {} Type '{}' is already declared (records and enums share names)",
+                    error_line_start(),
+                    format_name(name)
+                );
+                eprintln!(
+                    "  {} First declared at line {}",
+                    error_line_end(),
+                    format_line(*first_line)
+                );
+            }
+            SemanticError::ConstAssigned {
+                name,
+                first_line,
+                line,
+            } => {
+                error_header("Assignment to a constant", *line);
+                eprintln!(
+                    "  {} '{}' is a constant, so it can't be assigned to",
+                    error_line_start(),
+                    format_name(name)
+                );
+                eprintln!(
+                    "  {} Declared as const at line {}",
+                    error_line_end(),
+                    format_line(*first_line)
+                );
+            }
+            SemanticError::ConstNotConstant { name, line } => {
+                error_header("Constant value not known at compile time", *line);
+                eprintln!(
+                    "  {} Constant '{}' has to be set to a literal (ex. 10, \"hi\", true) or an enum variant",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::ConstNotGlobal { name, line } => {
+                error_header("Constant not at top level", *line);
+                eprintln!(
+                    "  {} Constant '{}' has to be declared outside of any function or block",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::FunctionNotTopLevel { name, line } => {
+                error_header("Function not at top level", *line);
+                eprintln!(
+                    "  {} Function '{}' has to be declared outside of any function or block",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::RecordNotTopLevel { name, line } => {
+                error_header("Record not at top level", *line);
+                eprintln!(
+                    "  {} Record '{}' has to be declared outside of any function or block",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::EnumNotTopLevel { name, line } => {
+                error_header("Enum not at top level", *line);
+                eprintln!(
+                    "  {} Enum '{}' has to be declared outside of any function or block",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::GlobalUsedBeforeInitialized {
+                global,
+                global_line,
+                function,
+                line,
+            } => {
+                error_header("Global used before it's set", *line);
+                eprintln!(
+                    "  {} Calling '{}' here uses the global '{}', which isn't set yet",
+                    error_line_start(),
+                    format_name(function),
+                    format_name(global)
+                );
+                eprintln!(
+                    "  {} '{}' is set at line {}; call '{}' below that",
+                    error_line_end(),
+                    format_name(global),
+                    format_line(*global_line),
+                    format_name(function)
+                );
+            }
+            SemanticError::EnumVariantAlreadyDeclared {
+                enum_name,
+                variant,
+                line,
+            } => {
+                error_header("Duplicate enum variant", *line);
+                eprintln!(
+                    "  {} Enum '{}' already has a variant named '{}'",
+                    error_line_start(),
+                    format_name(enum_name),
+                    format_name(variant)
+                );
+            }
+            SemanticError::EnumVariantNotFound {
+                enum_name,
+                variant,
+                line,
+            } => {
+                error_header("Enum variant not found", *line);
+                eprintln!(
+                    "  {} Enum '{}' has no variant named '{}'",
+                    error_line_start(),
+                    format_name(enum_name),
+                    format_name(variant)
+                );
+            }
+            SemanticError::RecordNameConflict { name, line } => {
+                error_header("Record name conflict", *line);
+                eprintln!(
+                    "  {} '{}' is already the name of a function",
+                    error_line_start(),
+                    format_name(name)
+                );
+                eprintln!(
+                    "  {} Records are built like function calls, so they need their own name",
+                    error_line_end()
+                );
+            }
+            SemanticError::RecordHasNoFields { name, line } => {
+                error_header("Empty record", *line);
+                eprintln!(
+                    "  {} Record '{}' needs at least one field",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::RecordContainsItself { name, line } => {
+                error_header("Record contains itself", *line);
+                eprintln!(
+                    "  {} Record '{}' holds itself, so it would never end",
+                    error_line_start(),
+                    format_name(name)
+                );
+                eprintln!(
+                    "  {} Use a List of it instead, which can be empty",
+                    error_line_end()
+                );
+            }
+            SemanticError::TypeNotDeclared { name, line } => {
+                error_header("Type not declared", *line);
+                eprintln!(
+                    "  {} Type '{}' is not declared",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::BuiltinArgumentInvalid {
+                name,
+                expected,
+                found,
+                line,
+            } => {
+                error_header("Invalid argument", *line);
+                eprintln!(
+                    "  {} {} expects {}",
+                    error_line_start(),
+                    format_name(name),
+                    expected
+                );
+                eprintln!(
+                    "  {} Found:    {}",
+                    error_line_end(),
+                    format_type_error(found)
+                );
+            }
+            SemanticError::FunctionNameReserved { name, line } => {
+                error_header("Reserved function name", *line);
+                eprintln!(
+                    "  {} {} is a builtin function; pick a different name",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::FunctionAlreadyDeclared {
+                signature,
+                first_line,
+                redeclaration_line,
+            } => {
+                error_header("Function already declared", *redeclaration_line);
+                eprintln!(
+                    "  {} Function '{}' is already declared; overloads need different parameter types",
+                    error_line_start(),
+                    format_name(signature)
+                );
+                eprintln!(
+                    "  {} First declared at line {}",
+                    error_line_end(),
+                    format_line(*first_line)
+                );
+            }
+            SemanticError::ArgumentTypeMismatch {
+                function,
+                parameter,
+                position,
+                expected,
+                found,
+                line,
+            } => {
+                error_header("Argument type mismatch", *line);
+                eprintln!(
+                    "  {} Argument {} of '{}' is passed to parameter '{}'",
+                    error_line_start(),
+                    format_line(*position as u32),
+                    format_name(function),
+                    format_name(parameter)
+                );
+                eprintln!(
+                    "  {} Expected: {}",
+                    error_line_middle(),
+                    format_type(expected)
+                );
+                eprintln!(
+                    "  {} Found:    {}",
+                    error_line_end(),
+                    format_type_error(found)
+                );
+            }
+            SemanticError::FunctionCallAmbiguous {
+                name,
+                candidates,
+                through_defaults,
+                line,
+            } => {
+                error_header("Ambiguous function call", *line);
+                eprintln!(
+                    "  {} This call to '{}' could go to more than one overload: {}",
+                    error_line_start(),
+                    format_name(name),
+                    format_name(&candidates.join(", "))
+                );
+                if *through_defaults {
+                    eprintln!(
+                        "  {} Default parameters make these overloads take the same arguments; pass the defaulted arguments, or remove a default",
+                        error_line_end()
+                    );
+                } else {
+                    eprintln!(
+                        "  {} Make the argument types clear (ex. use a variable with a declared type)",
+                        error_line_end()
+                    );
+                }
+            }
+            SemanticError::NoMatchingOverload {
+                name,
+                found,
+                overloads,
+                line,
+            } => {
+                let found: Vec<String> = found.iter().map(|arg| arg.to_plank_str()).collect();
+                error_header("No matching overload", *line);
+                eprintln!(
+                    "  {} '{}' has no overload that takes ({})",
+                    error_line_start(),
+                    format_name(name),
+                    found.join(", ").red().bold()
+                );
+                eprintln!(
+                    "  {} Overloads: {}",
+                    error_line_end(),
+                    format_name(&overloads.join(", "))
+                );
+            }
+            SemanticError::NumberLiteralOutOfRange { literal, line } => {
+                error_header("Number literal out of range", *line);
+                eprintln!(
+                    "  {} Number '{}' doesn't fit in a 64 bit integer",
+                    error_line_start(),
+                    format_name(literal)
+                );
+                eprintln!(
+                    "  {} Numbers must be between {} and {}",
+                    error_line_end(),
+                    i64::MIN,
+                    i64::MAX
+                );
+            }
+            SemanticError::ArgumentMissing {
+                function,
+                parameter,
+                line,
+            } => {
+                error_header("Missing argument", *line);
+                eprintln!(
+                    "  {} Nothing is passed to parameter '{}' of '{}', which has no default",
+                    error_line_start(),
+                    format_name(parameter),
+                    format_name(function)
+                );
+            }
+            SemanticError::ArgumentNameNotFound {
+                function,
+                name,
+                line,
+            } => {
+                error_header("Argument name not found", *line);
+                eprintln!(
+                    "  {} '{}' has no parameter called '{}'",
+                    error_line_start(),
+                    format_name(function),
+                    name.red().bold()
+                );
+            }
+            SemanticError::ArgumentGivenTwice {
+                function,
+                parameter,
+                line,
+            } => {
+                error_header("Argument given twice", *line);
+                eprintln!(
+                    "  {} Parameter '{}' of '{}' is passed more than one argument",
+                    error_line_start(),
+                    format_name(parameter),
+                    format_name(function)
+                );
+            }
+            SemanticError::PositionalArgumentAfterNamed {
+                function,
+                position,
+                line,
+            } => {
+                error_header("Positional argument after named argument", *line);
+                eprintln!(
+                    "  {} Argument {} of '{}' has no name, but comes after an argument passed by name",
+                    error_line_start(),
+                    format_line(*position as u32),
+                    format_name(function)
+                );
+                eprintln!(
+                    "  {} Pass arguments by position first, then by name",
+                    error_line_end()
+                );
+            }
+            SemanticError::DefaultNotConstant {
+                function,
+                parameter,
+                line,
+            } => {
+                error_header("Default value not known at compile time", *line);
+                eprintln!(
+                    "  {} Parameter '{}' of '{}' has to default to a literal (ex. 10, \"hi\", true) or an enum variant",
+                    error_line_start(),
+                    format_name(parameter),
+                    format_name(function)
+                );
+            }
+            SemanticError::InterpolatedValueInvalid { found, line } => {
+                error_header("Invalid value in string", *line);
+                eprintln!(
+                    "  {} Only Numbers, Strings, Booleans and enum variants can be put in a string with {{}}",
+                    error_line_start()
+                );
+                eprintln!(
+                    "  {} Found:    {}",
+                    error_line_end(),
+                    format_type_error(found)
+                );
+            }
+            SemanticError::PrintSeparatorInvalid { found, line } => {
+                error_header("Invalid print separator", *line);
+                eprintln!(
+                    "  {} The separator printed between arguments has to be a String",
+                    error_line_start()
+                );
+                eprintln!(
+                    "  {} Found:    {}",
+                    error_line_end(),
+                    format_type_error(found)
+                );
+            }
+            SemanticError::BuiltinArgumentNamed { name, line } => {
+                error_header("Named argument to a builtin", *line);
+                eprintln!(
+                    "  {} '{}' is a builtin, which only takes arguments by position",
+                    error_line_start(),
+                    format_name(name)
+                );
+            }
+            SemanticError::TypeNotInferred { name, line } => {
+                error_header("Type not inferred", *line);
+                eprintln!(
+                    "  {} 'let {}' is set to something without a value, so it has no type to take",
+                    error_line_start(),
+                    format_name(name)
+                );
+                eprintln!(
+                    "  {} Set it to a value, or write its type instead of 'let'",
+                    error_line_end()
+                );
+            }
+            SemanticError::DefaultTypeMismatch {
+                function,
+                parameter,
+                expected,
+                found,
+                line,
+            } => {
+                error_header("Default value type mismatch", *line);
+                eprintln!(
+                    "  {} Default of parameter '{}' of '{}'",
+                    error_line_start(),
+                    format_name(parameter),
+                    format_name(function)
+                );
+                eprintln!(
+                    "  {} Expected: {}",
+                    error_line_middle(),
+                    format_type(expected)
+                );
+                eprintln!(
+                    "  {} Found:    {}",
+                    error_line_end(),
+                    format_type_error(found)
+                );
+            }
         }
     }
 }
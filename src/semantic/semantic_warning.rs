@@ -0,0 +1,118 @@
+use colored::*;
+
+/// Things the analyzer found that are probably mistakes, but don't stop the
+/// program from compiling.
+#[derive(Debug, Clone)]
+pub enum SemanticWarning {
+    DuplicateMatchCase {
+        value: String,
+        line: u32,
+        first_line: u32,
+    },
+    MatchMissingDefault {
+        line: u32,
+    },
+    /// A match over an enum, or an if/elseIf chain comparing the same value
+    /// against variants of an enum, has no default/else and leaves some of
+    /// the variants out.
+    UnhandledEnumVariants {
+        /// "match" or "if chain".
+        construct: &'static str,
+        enum_name: String,
+        variants: Vec<String>,
+        line: u32,
+    },
+    /// Nothing that runs when the file runs ever calls this function.
+    FunctionNeverCalled {
+        name: String,
+        line: u32,
+    },
+}
+
+fn warning_header(title: &str, line: u32) {
+    eprintln!(
+        "{} {} (line {})",
+        "⚠ Warning:".bold().yellow(),
+        title.bold(),
+        format_line(line)
+    );
+}
+
+fn warning_line_start() -> ColoredString {
+    "┌─".cyan()
+}
+
+fn warning_line_end() -> ColoredString {
+    "└─".cyan()
+}
+
+fn format_name(name: &str) -> ColoredString {
+    name.yellow().bold()
+}
+
+fn format_line(line: u32) -> ColoredString {
+    line.to_string().blue().bold()
+}
+
+impl SemanticWarning {
+    pub fn print_warning(&self) {
+        match self {
+            SemanticWarning::DuplicateMatchCase {
+                value,
+                line,
+                first_line,
+            } => {
+                warning_header("Duplicate match case", *line);
+                eprintln!(
+                    "  {} Case {} was already handled on line {}",
+                    warning_line_start(),
+                    format_name(value),
+                    format_line(*first_line)
+                );
+                eprintln!("  {} This case will never be reached", warning_line_end());
+            }
+            SemanticWarning::MatchMissingDefault { line } => {
+                warning_header("Match has no default", *line);
+                eprintln!(
+                    "  {} Nothing happens when the value doesn't match any case; add a {} to handle it",
+                    warning_line_start(),
+                    format_name("default:")
+                );
+            }
+            SemanticWarning::UnhandledEnumVariants {
+                construct,
+                enum_name,
+                variants,
+                line,
+            } => {
+                let (arm, fallback_article, fallback) = match *construct {
+                    "match" => ("a case", "a", "default:"),
+                    _ => ("an arm", "an", "else"),
+                };
+                warning_header("Enum variants not handled", *line);
+                eprintln!(
+                    "  {} This {} over {} doesn't handle: {}",
+                    warning_line_start(),
+                    construct,
+                    format_name(enum_name),
+                    format_name(&variants.join(", "))
+                );
+                eprintln!(
+                    "  {} Add {} for them, or {} {} to handle everything else",
+                    warning_line_end(),
+                    arm,
+                    fallback_article,
+                    format_name(fallback)
+                );
+            }
+            SemanticWarning::FunctionNeverCalled { name, line } => {
+                warning_header("Function never called", *line);
+                eprintln!(
+                    "  {} '{}' isn't called by anything that runs (calls from itself, or from other functions that are never called, don't count)",
+                    warning_line_start(),
+                    format_name(name)
+                );
+            }
+        }
+    }
+}
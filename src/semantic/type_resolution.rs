@@ -1,4 +1,5 @@
 use crate::ast::GeneralOperator;
+use crate::ast::Power;
 use crate::ast::Statement;
 use crate::ast::Term;
 use crate::ast::Unary;
@@ -6,13 +7,128 @@ use crate::ast::Value;
 use crate::ast::ValueType;
 use crate::ast::VariableAssignmentStatement;
 use crate::ast::VariableDeclarationStatement;
-use crate::ast::{Comparison, DataType, Expression, FunctionTable, Logical};
+use crate::ast::{Accessor, AccessorKind, TypeTable};
+use crate::ast::{
+    Comparison, DataType, Expression, FunctionSymbol, FunctionTable, Logical, Parameter,
+};
 use crate::ast::{convert_expression_op_to_general, convert_term_op_to_general};
+use crate::builtins::get_builtin;
 use crate::symbol_table::SymbolTable;
 
+/// Which overload of a function a call goes to.
+pub enum OverloadResolution<'a> {
+    Found(&'a FunctionSymbol),
+    /// There's no function with that name.
+    NotDeclared,
+    /// More than one overload takes these arguments.
+    Ambiguous(Vec<&'a FunctionSymbol>),
+    /// None of the overloads take these arguments.
+    NoMatch(Vec<&'a FunctionSymbol>),
+}
+
+/// Why the arguments of a call can't be matched up with the parameters.
+pub enum ArgumentBindingError {
+    TooManyArguments,
+    /// Nothing was passed to a parameter without a default.
+    MissingArgument(String),
+    /// There's no parameter with the name an argument was passed with.
+    NameNotFound(String),
+    /// The parameter was given an argument more than once.
+    GivenTwice(String),
+    /// An argument passed by position came after one passed by name
+    /// (the position of the argument, starting at 1).
+    PositionalAfterNamed(usize),
+}
+
+/// Matches up the arguments of a call with the parameters they're passed to.
+/// Arguments passed by position go to the parameters in order, then the ones
+/// passed by name go to the parameter with that name (greet("x", greeting: "yo")).
+/// Returns the index of the argument each parameter gets, or None when it
+/// gets its default.
+pub fn bind_arguments(
+    parameters: &[Parameter],
+    argument_names: &[Option<String>],
+    argument_count: usize,
+) -> Result<Vec<Option<usize>>, ArgumentBindingError> {
+    let mut bindings: Vec<Option<usize>> = vec![None; parameters.len()];
+    let mut seen_named = false;
+
+    for idx in 0..argument_count {
+        let param_idx = match argument_names.get(idx).cloned().flatten() {
+            Some(name) => {
+                seen_named = true;
+                parameters
+                    .iter()
+                    .position(|param| param.name == name)
+                    .ok_or(ArgumentBindingError::NameNotFound(name))?
+            }
+            None if seen_named => {
+                return Err(ArgumentBindingError::PositionalAfterNamed(idx + 1));
+            }
+            None if idx < parameters.len() => idx,
+            None => return Err(ArgumentBindingError::TooManyArguments),
+        };
+
+        if bindings[param_idx].is_some() {
+            let name = parameters[param_idx].name.clone();
+            return Err(ArgumentBindingError::GivenTwice(name));
+        }
+        bindings[param_idx] = Some(idx);
+    }
+
+    for (param, binding) in parameters.iter().zip(&bindings) {
+        if binding.is_none() && param.default.is_none() {
+            return Err(ArgumentBindingError::MissingArgument(param.name.clone()));
+        }
+    }
+
+    Ok(bindings)
+}
+
+/// Picks the overload whose parameter types are the types of the arguments.
+/// An argument whose type couldn't be worked out (ex. an empty list) could be
+/// passed to any parameter.
+/// A function that isn't overloaded is always picked, so that the arguments
+/// can be checked against it.
+pub fn resolve_overload<'a>(
+    function_header_map: &'a FunctionTable,
+    function_name: &str,
+    arg_types: &[DataType],
+    arg_names: &[Option<String>],
+) -> OverloadResolution<'a> {
+    let overloads = function_header_map.get_overloads(function_name);
+
+    let candidates: Vec<&FunctionSymbol> = overloads
+        .iter()
+        .copied()
+        .filter(|def| {
+            let Ok(bindings) = bind_arguments(&def.parameters, arg_names, arg_types.len()) else {
+                return false;
+            };
+            def.parameters
+                .iter()
+                .zip(bindings)
+                .all(|(param, binding)| match binding {
+                    Some(idx) => {
+                        arg_types[idx] == DataType::Invalid || param.data_type == arg_types[idx]
+                    }
+                    None => true,
+                })
+        })
+        .collect();
+
+    match (overloads.as_slice(), candidates.as_slice()) {
+        ([], _) => OverloadResolution::NotDeclared,
+        (_, [def]) | ([def], _) => OverloadResolution::Found(def),
+        (_, []) => OverloadResolution::NoMatch(overloads),
+        _ => OverloadResolution::Ambiguous(candidates),
+    }
+}
+
 pub fn resolve_logical_values(
     logical: &mut Logical,
     function_header_map: &FunctionTable,
+    type_table: &TypeTable,
     symbol_table: &SymbolTable,
 ) {
     // If no logical operators, the comparison drives what type this is.
@@ -20,6 +136,7 @@ pub fn resolve_logical_values(
         resolve_comparison_values(
             logical.comparisons.get_mut(0).unwrap(), // sketch
             function_header_map,
+            type_table,
             symbol_table,
         );
         logical.data_type = logical.comparisons[0].data_type.clone(); // little sketch
@@ -29,7 +146,7 @@ pub fn resolve_logical_values(
 
     // if any of the our comparisons are invalid, this entire logical is invalid.
     for (idx, comparison) in &mut logical.comparisons.iter_mut().enumerate() {
-        resolve_comparison_values(comparison, function_header_map, symbol_table);
+        resolve_comparison_values(comparison, function_header_map, type_table, symbol_table);
         if comparison.data_type == DataType::Invalid {
             logical.data_type = DataType::Invalid;
         }
@@ -45,6 +162,7 @@ pub fn resolve_logical_values(
 fn resolve_comparison_values(
     comparison: &mut Comparison,
     function_header_map: &FunctionTable,
+    type_table: &TypeTable,
     symbol_table: &SymbolTable,
 ) {
     // If no comparison ops, then the type is driven by the sole expression.
@@ -53,6 +171,7 @@ fn resolve_comparison_values(
         resolve_expression_values_and_update_data_type(
             comparison.expressions.get_mut(0).unwrap(),
             function_header_map,
+            type_table,
             symbol_table,
         );
         comparison.data_type = comparison.expressions[0].data_type.clone();
@@ -62,7 +181,12 @@ fn resolve_comparison_values(
     let mut all_expr_type = DataType::Unknown;
 
     for (idx, expr) in &mut comparison.expressions.iter_mut().enumerate() {
-        resolve_expression_values_and_update_data_type(expr, function_header_map, symbol_table);
+        resolve_expression_values_and_update_data_type(
+            expr,
+            function_header_map,
+            type_table,
+            symbol_table,
+        );
 
         if idx == 0 {
             all_expr_type = expr.data_type.clone();
@@ -88,10 +212,16 @@ fn resolve_comparison_values(
 fn resolve_expression_values_and_update_data_type(
     expression: &mut Expression,
     function_header_map: &FunctionTable,
+    type_table: &TypeTable,
     symbol_table: &SymbolTable,
 ) {
     for (idx, term) in &mut expression.terms.iter_mut().enumerate() {
-        resolve_term_values_and_update_data_type(term, function_header_map, symbol_table);
+        resolve_term_values_and_update_data_type(
+            term,
+            function_header_map,
+            type_table,
+            symbol_table,
+        );
 
         if idx == 0 {
             expression.data_type = term.data_type.clone();
@@ -110,10 +240,16 @@ fn resolve_expression_values_and_update_data_type(
 fn resolve_term_values_and_update_data_type(
     term: &mut Term,
     function_header_map: &FunctionTable,
+    type_table: &TypeTable,
     symbol_table: &SymbolTable,
 ) {
     for (idx, unary) in &mut term.unarys.iter_mut().enumerate() {
-        resolve_unary_values_and_update_data_type(unary, function_header_map, symbol_table);
+        resolve_unary_values_and_update_data_type(
+            unary,
+            function_header_map,
+            type_table,
+            symbol_table,
+        );
 
         if idx == 0 {
             term.data_type = unary.data_type.clone();
@@ -132,38 +268,99 @@ fn resolve_term_values_and_update_data_type(
 fn resolve_unary_values_and_update_data_type(
     unary: &mut Unary,
     function_header_map: &FunctionTable,
+    type_table: &TypeTable,
     symbol_table: &SymbolTable,
 ) {
-    resolve_value(&mut unary.primary, function_header_map, symbol_table);
+    resolve_power_values_and_update_data_type(
+        &mut unary.power,
+        function_header_map,
+        type_table,
+        symbol_table,
+    );
 
-    unary.data_type = unary.primary.data_type.clone();
+    unary.data_type = unary.power.data_type.clone();
+}
+
+fn resolve_power_values_and_update_data_type(
+    power: &mut Power,
+    function_header_map: &FunctionTable,
+    type_table: &TypeTable,
+    symbol_table: &SymbolTable,
+) {
+    for (idx, value) in &mut power.values.iter_mut().enumerate() {
+        resolve_value(value, function_header_map, type_table, symbol_table);
+
+        if idx == 0 {
+            power.data_type = value.data_type.clone();
+            continue;
+        }
+
+        // If there are conflicting types (which is not allowed),
+        // set the datatype to invalid and return early.
+        if power.data_type != value.data_type {
+            power.data_type = DataType::Invalid;
+            break;
+        }
+    }
 }
 
 pub fn resolve_value(
     val: &mut Value,
     function_header_map: &FunctionTable,
+    type_table: &TypeTable,
     symbol_table: &SymbolTable,
 ) {
     let val_type = val.value_type.clone();
     match val_type {
+        // Builtins are looked up before user functions, which can't share their names.
+        ValueType::FunctionCall if get_builtin(&val.raw_text).is_some() => {
+            for param in val.params.iter_mut() {
+                resolve_logical_values(param, function_header_map, type_table, symbol_table);
+            }
+            // Whether the arguments are right is checked in validate.
+            val.data_type = get_builtin(&val.raw_text)
+                .map(|builtin| builtin.return_type.clone())
+                .unwrap_or(DataType::Invalid);
+        }
+        // Point(1, 2) is building a record, not calling a function.
+        ValueType::FunctionCall | ValueType::RecordConstruction
+            if type_table.contains_record(&val.raw_text) =>
+        {
+            val.value_type = ValueType::RecordConstruction;
+            for param in val.params.iter_mut() {
+                resolve_logical_values(param, function_header_map, type_table, symbol_table);
+            }
+            if let Some(record) = type_table.get_record(&val.raw_text) {
+                fill_in_arguments(val, &record.fields, function_header_map, type_table);
+            }
+            // Whether the fields are right is checked in validate.
+            val.data_type = DataType::Record(val.raw_text.clone());
+        }
         // TODO: could this be moved into a more generic 'resolve value' function?
         ValueType::FunctionCall => {
-            let func_call_decl_op = function_header_map.get_func_def_using_str(&val.raw_text);
-
-            match func_call_decl_op {
-                Some(func_decl) => {
-                    val.data_type = func_decl.return_type.clone();
-                    // TODO: we need to also resolve all of the logicals passed into the parameters of this function.
-                    // How should this be done?
-                    for param in val.params.iter_mut() {
-                        // TODO: resolve logical here. Should work since we're passing a mutable reference,
-                        // and only once at a time.
-                        resolve_logical_values(param, function_header_map, symbol_table);
-                    }
-                }
-                None => {
-                    println!("NONE was found when trying to set the return type :(");
+            // The arguments decide which overload is being called.
+            for param in val.params.iter_mut() {
+                resolve_logical_values(param, function_header_map, type_table, symbol_table);
+            }
+            let arg_types: Vec<DataType> = val
+                .params
+                .iter()
+                .map(|param| param.data_type.clone())
+                .collect();
+
+            // When there isn't exactly one overload to call, it's reported in validate.
+            match resolve_overload(
+                function_header_map,
+                &val.raw_text,
+                &arg_types,
+                &val.argument_names,
+            ) {
+                OverloadResolution::Found(func_def) => {
+                    val.data_type = func_def.return_type.clone();
+                    val.function_c_name = Some(function_header_map.get_c_name(func_def));
+                    fill_in_arguments(val, &func_def.parameters, function_header_map, type_table);
                 }
+                _ => val.data_type = DataType::Invalid,
             }
         }
         ValueType::Variable => {
@@ -171,11 +368,77 @@ pub fn resolve_value(
             // You can implement variable type resolution logic here
             let var_type_op = symbol_table.get(&val.raw_text);
             match var_type_op {
-                Some(var_type) => val.data_type = var_type.data_type.clone(),
+                Some(var_type) => {
+                    val.data_type = var_type.data_type.clone();
+                    val.is_global = var_type.is_global;
+                }
                 None => val.data_type = DataType::Invalid, // TODO: maybe do something? nah, this hsould be handled in analysis
             }
         }
-        ValueType::Expression
+        // Whether each value can be put in the string is checked in validate.
+        ValueType::InterpolatedString => {
+            for piece in val.params.iter_mut() {
+                resolve_logical_values(piece, function_header_map, type_table, symbol_table);
+            }
+            val.data_type = DataType::String;
+        }
+        ValueType::ListLiteral => {
+            // Every element has to be the same type, which decides the type
+            // of the list. An empty list has nothing to decide it with.
+            let mut element_type = DataType::Invalid;
+            for (idx, element) in val.params.iter_mut().enumerate() {
+                resolve_logical_values(element, function_header_map, type_table, symbol_table);
+
+                if idx == 0 {
+                    element_type = element.data_type.clone();
+                } else if element.data_type != element_type {
+                    element_type = DataType::Invalid;
+                }
+            }
+
+            val.data_type = match element_type {
+                DataType::Invalid => DataType::Invalid,
+                _ => DataType::List(Box::new(element_type)),
+            };
+        }
+        // Color.Red; a variable with the same name as the enum hides it.
+        ValueType::Access | ValueType::EnumVariant
+            if symbol_table.get(&val.raw_text).is_none()
+                && type_table.contains_enum(&val.raw_text) =>
+        {
+            val.value_type = ValueType::EnumVariant;
+            let enum_symbol = type_table.get_enum(&val.raw_text);
+            val.data_type = match (enum_symbol, val.accessors.as_slice()) {
+                (Some(enum_symbol), [accessor]) if matches!(&accessor.kind, AccessorKind::Field(variant) if enum_symbol.contains_variant(variant)) => {
+                    DataType::Enum(val.raw_text.clone())
+                }
+                _ => DataType::Invalid,
+            };
+        }
+        ValueType::Access => {
+            let var_type = match symbol_table.get(&val.raw_text) {
+                Some(var) => {
+                    val.is_global = var.is_global;
+                    var.data_type.clone()
+                }
+                None => DataType::Invalid,
+            };
+            val.data_type = resolve_accessors(
+                var_type,
+                &mut val.accessors,
+                function_header_map,
+                type_table,
+                symbol_table,
+            );
+        }
+        ValueType::Expression => {
+            if let Some(nested) = val.params.first_mut() {
+                resolve_logical_values(nested, function_header_map, type_table, symbol_table);
+                val.data_type = nested.data_type.clone();
+            }
+        }
+        ValueType::RecordConstruction
+        | ValueType::EnumVariant
         | ValueType::InlineNumber
         | ValueType::InlineString
         | ValueType::InlineBoolean
@@ -184,3 +447,82 @@ pub fn resolve_value(
         }
     }
 }
+
+/// Puts the arguments of a call in the order of the parameters, with the
+/// default of every parameter that wasn't passed anything, so the call can be
+/// written out like any other in c.
+/// Left alone if the arguments don't match up with the parameters, or a
+/// default isn't the right type; both are reported in validate.
+fn fill_in_arguments(
+    val: &mut Value,
+    parameters: &[Parameter],
+    function_header_map: &FunctionTable,
+    type_table: &TypeTable,
+) {
+    let Ok(bindings) = bind_arguments(parameters, &val.argument_names, val.params.len()) else {
+        return;
+    };
+
+    let mut filled_in = Vec::new();
+    for (param, binding) in parameters.iter().zip(bindings) {
+        match (binding, &param.default) {
+            (Some(idx), _) => filled_in.push(val.params[idx].clone()),
+            (None, Some(default)) => {
+                // Defaults are constants, so they don't need any variables.
+                let mut default = default.clone();
+                resolve_logical_values(
+                    &mut default,
+                    function_header_map,
+                    type_table,
+                    &SymbolTable::new(),
+                );
+                if default.data_type != param.data_type {
+                    return;
+                }
+                filled_in.push(default);
+            }
+            (None, None) => return,
+        }
+    }
+
+    val.params = filled_in;
+    val.argument_names.clear();
+}
+
+/// Goes through every step of an access (ps[i].x), starting at the type of the
+/// variable, and returns the type of whatever's at the end of it.
+/// Every index goes one list deeper (if xs is a List<List<Number>>, xs[i] is a
+/// List<Number>), and every field is the type it was declared as in its record.
+/// Once a step doesn't make sense, it and everything after it is Invalid.
+pub fn resolve_accessors(
+    var_type: DataType,
+    accessors: &mut [Accessor],
+    function_header_map: &FunctionTable,
+    type_table: &TypeTable,
+    symbol_table: &SymbolTable,
+) -> DataType {
+    let mut data_type = var_type;
+
+    for accessor in accessors.iter_mut() {
+        data_type = match &mut accessor.kind {
+            AccessorKind::Index(index) => {
+                resolve_logical_values(index, function_header_map, type_table, symbol_table);
+                match data_type {
+                    DataType::List(element_type) => *element_type,
+                    _ => DataType::Invalid,
+                }
+            }
+            AccessorKind::Field(field_name) => match &data_type {
+                DataType::Record(record_name) => type_table
+                    .get_record(record_name)
+                    .and_then(|record| record.get_field_type(field_name))
+                    .cloned()
+                    .unwrap_or(DataType::Invalid),
+                _ => DataType::Invalid,
+            },
+        };
+        accessor.data_type = data_type.clone();
+    }
+
+    data_type
+}
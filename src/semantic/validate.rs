@@ -1,26 +1,59 @@
+use crate::ast::{Accessor, AccessorKind};
 use crate::ast::{
-    Comparison, ComparisonOperator, DataType, Expression, Logical, Term, Unary, Value, ValueType,
+    Comparison, ComparisonOperator, DataType, Expression, FunctionSymbol, FunctionTable,
+    GeneralOperator, Logical, Power, Term, TypeTable, Unary, UnaryOperator, Value, ValueType,
+    convert_comparison_op_to_general, convert_expression_op_to_general,
+    convert_logical_op_to_general, convert_term_op_to_general, convert_unary_op_to_general,
 };
+use crate::builtins::{get_builtin, is_builtin_function};
 use crate::semantic::SemanticError;
+use crate::semantic::type_resolution::{
+    ArgumentBindingError, OverloadResolution, bind_arguments, resolve_overload,
+};
 
 /// Validates that the logical has a valid type, and doesn't break any rules.
 /// If the returned vec is empty, then that means everything is okay.
 /// Typcially called after resolving a logical's datatype.
 /// NOTE: just a direct call to get_operation_errors_logical, but leaving just
 /// in case we add more tests here, and it can be encapsulated in this function.
-pub fn validate_logical(logical: &Logical, line: u32) -> Vec<SemanticError> {
+pub fn validate_logical(
+    logical: &Logical,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Vec<SemanticError> {
     let mut errors: Vec<SemanticError> = Vec::new();
 
     // make sure operations are being used correctly.
-    let errors_prop_ops = get_operation_errors_logical(logical, line);
+    let errors_prop_ops = get_operation_errors_logical(logical, function_table, type_table, line);
     errors.extend(errors_prop_ops);
 
     errors
 }
 
-fn get_operation_errors_logical(logical: &Logical, line: u32) -> Option<SemanticError> {
+/// Same as validate_logical, for a value that isn't inside of a logical
+/// (ex. a function call on its own line).
+pub fn validate_value(
+    value: &Value,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Vec<SemanticError> {
+    get_operation_errors_value(value, function_table, type_table, line)
+        .into_iter()
+        .collect()
+}
+
+fn get_operation_errors_logical(
+    logical: &Logical,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
     for comparison in &logical.comparisons {
-        if let Some(error) = get_operation_errors_comparison(comparison, line) {
+        if let Some(error) =
+            get_operation_errors_comparison(comparison, function_table, type_table, line)
+        {
             return Some(error);
         }
     }
@@ -35,13 +68,14 @@ fn get_operation_errors_logical(logical: &Logical, line: u32) -> Option<Semantic
 
     // These all need to be booleans for this to be valid. Logical operators
     // are only used between booleans.
-    for comparison in &logical.comparisons {
+    for (idx, comparison) in logical.comparisons.iter().enumerate() {
         if comparison.data_type != DataType::Boolean {
-            return Some(SemanticError::UnexpectedStatement {
+            // The operator next to the comparison that isn't a boolean.
+            let operator = logical.operators[idx.saturating_sub(1)].clone();
+            return Some(SemanticError::OperatorTypeInvalid {
+                operator: convert_logical_op_to_general(operator).unwrap_or(GeneralOperator::And),
+                found: comparison.data_type.clone(),
                 line,
-                explanation:
-                    "logical cannot have && or || if string or number TODO make this better"
-                        .to_string(),
             });
         }
     }
@@ -49,9 +83,16 @@ fn get_operation_errors_logical(logical: &Logical, line: u32) -> Option<Semantic
     None
 }
 
-fn get_operation_errors_comparison(comparison: &Comparison, line: u32) -> Option<SemanticError> {
+fn get_operation_errors_comparison(
+    comparison: &Comparison,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
     for expression in &comparison.expressions {
-        if let Some(error) = get_operation_errors_expression(expression, line) {
+        if let Some(error) =
+            get_operation_errors_expression(expression, function_table, type_table, line)
+        {
             return Some(error);
         }
     }
@@ -60,9 +101,15 @@ fn get_operation_errors_comparison(comparison: &Comparison, line: u32) -> Option
         return None;
     }
 
-    // strings can have == or !=
-    // number can do whatever they want
-    // bool can have ==, !=,
+    // a < b < c would be compiled as (a < b) < c in c, which compares a
+    // boolean to c. Make the user spell out what they mean with 'and'.
+    if comparison.operators.len() > 1 {
+        return Some(SemanticError::ComparisonChained { line });
+    }
+
+    // strings and numbers can do whatever they want (strings are ordered
+    // alphabetically)
+    // bool can have ==, !=
     let shared_expressions_type = &comparison.expressions[0].data_type;
     for expression in comparison.expressions.iter() {
         if expression.data_type != *shared_expressions_type {
@@ -75,36 +122,52 @@ fn get_operation_errors_comparison(comparison: &Comparison, line: u32) -> Option
         }
     }
 
-    if comparison.expressions.len() > 1
-        && matches!(
-            shared_expressions_type,
-            DataType::Boolean | DataType::String
-        )
+    // Lists and records can't be compared at all.
+    if matches!(
+        shared_expressions_type,
+        DataType::List(_) | DataType::Record(_)
+    ) && let Some(operator) = convert_comparison_op_to_general(comparison.operators[0].clone())
     {
+        return Some(SemanticError::OperatorTypeInvalid {
+            operator,
+            found: shared_expressions_type.clone(),
+            line,
+        });
+    }
+
+    // Booleans and enums aren't ordered, they can only be checked for equality.
+    if matches!(
+        shared_expressions_type,
+        DataType::Boolean | DataType::Enum(_)
+    ) {
         for op in comparison.operators.iter() {
             let does_use_invalid_op = !matches!(
                 op,
                 ComparisonOperator::equalequal | ComparisonOperator::notequal
             );
-            let mut optional_err: Option<SemanticError> = None;
-            if does_use_invalid_op {
-                optional_err = Some(SemanticError::UnexpectedStatement {
+            if does_use_invalid_op
+                && let Some(operator) = convert_comparison_op_to_general(op.clone())
+            {
+                return Some(SemanticError::OperatorTypeInvalid {
+                    operator,
+                    found: shared_expressions_type.clone(),
                     line,
-                    explanation:
-                        "comparison of type string/bool is using inappropriate operators TODO: improve this."
-                            .to_string(),
-                })
+                });
             }
-            return optional_err;
         }
     }
 
     None
 }
 
-fn get_operation_errors_expression(expression: &Expression, line: u32) -> Option<SemanticError> {
+fn get_operation_errors_expression(
+    expression: &Expression,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
     for term in &expression.terms {
-        if let Some(error) = get_operation_errors_term(term, line) {
+        if let Some(error) = get_operation_errors_term(term, function_table, type_table, line) {
             return Some(error);
         }
     }
@@ -122,24 +185,36 @@ fn get_operation_errors_expression(expression: &Expression, line: u32) -> Option
     }
 
     // Numbers do whatever they want
-    // Booleans and Strings aren't allowed at all, just add an error.
+    // Booleans, Strings, Lists, Records and Enums aren't allowed at all, just add an error.
     if expression.terms.len() > 1
-        && matches!(shared_term_type, DataType::Boolean | DataType::String)
+        && matches!(
+            shared_term_type,
+            DataType::Boolean
+                | DataType::String
+                | DataType::List(_)
+                | DataType::Record(_)
+                | DataType::Enum(_)
+        )
     {
-        return Some(SemanticError::UnexpectedStatement {
+        return Some(SemanticError::OperatorTypeInvalid {
+            operator: convert_expression_op_to_general(expression.operators[0].clone())
+                .unwrap_or(GeneralOperator::Plus),
+            found: shared_term_type.clone(),
             line,
-            explanation:
-                "expression cannot add/subtract from booleans or strings TODO: improve this err message"
-                    .to_string(),
         });
     }
 
     None
 }
 
-fn get_operation_errors_term(term: &Term, line: u32) -> Option<SemanticError> {
+fn get_operation_errors_term(
+    term: &Term,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
     for unary in &term.unarys {
-        if let Some(error) = get_operation_errors_unary(unary, line) {
+        if let Some(error) = get_operation_errors_unary(unary, function_table, type_table, line) {
             return Some(error);
         }
     }
@@ -157,31 +232,458 @@ fn get_operation_errors_term(term: &Term, line: u32) -> Option<SemanticError> {
     }
 
     // Numbers do whatever they want
-    // Booleans and Strings aren't allowed at all, just add an error.
-    if term.unarys.len() > 1 && matches!(shared_term_type, DataType::Boolean | DataType::String) {
-        return Some(SemanticError::UnexpectedStatement {
+    // Booleans, Strings, Lists, Records and Enums aren't allowed at all, just add an error.
+    if term.unarys.len() > 1
+        && matches!(
+            shared_term_type,
+            DataType::Boolean
+                | DataType::String
+                | DataType::List(_)
+                | DataType::Record(_)
+                | DataType::Enum(_)
+        )
+    {
+        return Some(SemanticError::OperatorTypeInvalid {
+            operator: convert_term_op_to_general(term.operations[0].clone())
+                .unwrap_or(GeneralOperator::Multiply),
+            found: shared_term_type.clone(),
             line,
-            explanation:
-                "term cannot add/subtract from booleans or strings TODO: improve this err message"
-                    .to_string(),
         });
     }
 
     None
 }
 
-fn get_operation_errors_unary(unary: &Unary, line: u32) -> Option<SemanticError> {
-    get_operation_errors_value(&unary.primary, line)
+fn get_operation_errors_unary(
+    unary: &Unary,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
+    if let Some(error) = get_operation_errors_power(&unary.power, function_table, type_table, line)
+    {
+        return Some(error);
+    }
+
+    // Negating only works on numbers, and not only works on booleans.
+    if let Some(operation) = &unary.operation {
+        let is_valid = match operation {
+            UnaryOperator::Negate => unary.power.data_type == DataType::Number,
+            UnaryOperator::Not => unary.power.data_type == DataType::Boolean,
+        };
+        if !is_valid && unary.power.data_type != DataType::Invalid {
+            return Some(SemanticError::OperatorTypeInvalid {
+                operator: convert_unary_op_to_general(operation.clone()),
+                found: unary.power.data_type.clone(),
+                line,
+            });
+        }
+    }
+
+    None
+}
+
+fn get_operation_errors_power(
+    power: &Power,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
+    for value in &power.values {
+        if let Some(error) = get_operation_errors_value(value, function_table, type_table, line) {
+            return Some(error);
+        }
+    }
+
+    // Only numbers can be raised to a power.
+    if power.values.len() > 1 {
+        for value in power.values.iter() {
+            if matches!(
+                value.data_type,
+                DataType::Boolean
+                    | DataType::String
+                    | DataType::List(_)
+                    | DataType::Record(_)
+                    | DataType::Enum(_)
+            ) {
+                return Some(SemanticError::OperatorTypeInvalid {
+                    operator: GeneralOperator::Power,
+                    found: value.data_type.clone(),
+                    line,
+                });
+            }
+        }
+    }
+
+    None
 }
 
-fn get_operation_errors_value(value: &Value, line: u32) -> Option<SemanticError> {
-    // Check if this is a function call with parameters
-    if value.value_type == ValueType::FunctionCall {
+fn get_operation_errors_value(
+    value: &Value,
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
+    if value.value_type == ValueType::InlineNumber && value.raw_text.parse::<i64>().is_err() {
+        return Some(SemanticError::NumberLiteralOutOfRange {
+            literal: value.raw_text.clone(),
+            line,
+        });
+    }
+
+    // Check if this is a function call (or list literal/record construction) with parameters
+    if matches!(
+        value.value_type,
+        ValueType::FunctionCall
+            | ValueType::ListLiteral
+            | ValueType::RecordConstruction
+            | ValueType::InterpolatedString
+            | ValueType::Expression
+    ) {
         for param in &value.params {
-            if let Some(error) = get_operation_errors_logical(param, line) {
+            if let Some(error) =
+                get_operation_errors_logical(param, function_table, type_table, line)
+            {
                 return Some(error);
             }
         }
     }
+
+    match value.value_type {
+        ValueType::ListLiteral => get_list_literal_errors(value, line),
+        ValueType::Access => get_accessor_errors(
+            &value.raw_text,
+            &value.accessors,
+            function_table,
+            type_table,
+            line,
+        ),
+        ValueType::RecordConstruction => get_record_construction_errors(value, type_table, line),
+        ValueType::EnumVariant => get_enum_variant_errors(value, line),
+        ValueType::InterpolatedString => get_interpolated_string_errors(value, line),
+        ValueType::FunctionCall if is_builtin_function(&value.raw_text) => {
+            get_builtin_call_errors(value, line)
+        }
+        ValueType::FunctionCall => get_function_call_errors(value, function_table, line),
+        _ => None,
+    }
+}
+
+/// Checks the arguments of a call against the parameters of the function it
+/// goes to. An argument that's already invalid has been reported on its own.
+fn get_argument_errors(
+    value: &Value,
+    function_def: &FunctionSymbol,
+    line: u32,
+) -> Option<SemanticError> {
+    let bindings = match bind_arguments(
+        &function_def.parameters,
+        &value.argument_names,
+        value.params.len(),
+    ) {
+        Ok(bindings) => bindings,
+        Err(error) => {
+            return Some(get_argument_binding_error(
+                error,
+                &function_def.identifier,
+                function_def.parameters.len(),
+                value.params.len(),
+                line,
+            ));
+        }
+    };
+
+    // Parameters left to their default were checked where the function is declared.
+    for (param, binding) in function_def.parameters.iter().zip(bindings) {
+        let Some(idx) = binding else {
+            continue;
+        };
+        let argument = &value.params[idx];
+        if argument.data_type != param.data_type && argument.data_type != DataType::Invalid {
+            return Some(SemanticError::ArgumentTypeMismatch {
+                function: function_def.identifier.clone(),
+                parameter: param.name.clone(),
+                position: idx + 1,
+                expected: param.data_type.clone(),
+                found: argument.data_type.clone(),
+                line,
+            });
+        }
+    }
+
+    None
+}
+
+/// name is the function (or record) being called.
+fn get_argument_binding_error(
+    error: ArgumentBindingError,
+    name: &str,
+    parameters_expected: usize,
+    parameters_provided: usize,
+    line: u32,
+) -> SemanticError {
+    let function = name.to_string();
+    match error {
+        ArgumentBindingError::TooManyArguments => SemanticError::IncorrectParameters {
+            parameters_expected,
+            parameters_provided,
+            line,
+        },
+        ArgumentBindingError::MissingArgument(parameter) => SemanticError::ArgumentMissing {
+            function,
+            parameter,
+            line,
+        },
+        ArgumentBindingError::NameNotFound(name) => SemanticError::ArgumentNameNotFound {
+            function,
+            name,
+            line,
+        },
+        ArgumentBindingError::GivenTwice(parameter) => SemanticError::ArgumentGivenTwice {
+            function,
+            parameter,
+            line,
+        },
+        ArgumentBindingError::PositionalAfterNamed(position) => {
+            SemanticError::PositionalArgumentAfterNamed {
+                function,
+                position,
+                line,
+            }
+        }
+    }
+}
+
+/// Whether the call goes to exactly one overload (see resolve_overload).
+fn get_function_call_errors(
+    value: &Value,
+    function_table: &FunctionTable,
+    line: u32,
+) -> Option<SemanticError> {
+    let arg_types: Vec<DataType> = value
+        .params
+        .iter()
+        .map(|param| param.data_type.clone())
+        .collect();
+
+    match resolve_overload(
+        function_table,
+        &value.raw_text,
+        &arg_types,
+        &value.argument_names,
+    ) {
+        OverloadResolution::Found(function_def) => get_argument_errors(value, function_def, line),
+        OverloadResolution::NotDeclared => Some(SemanticError::FunctionNotDeclared {
+            name: value.raw_text.clone(),
+            line,
+        }),
+        OverloadResolution::Ambiguous(candidates) => Some(SemanticError::FunctionCallAmbiguous {
+            name: value.raw_text.clone(),
+            candidates: candidates.iter().map(|def| def.to_plank_str()).collect(),
+            through_defaults: candidates
+                .iter()
+                .any(|def| def.parameters.len() > arg_types.len()),
+            line,
+        }),
+        OverloadResolution::NoMatch(overloads) => Some(SemanticError::NoMatchingOverload {
+            name: value.raw_text.clone(),
+            found: arg_types,
+            overloads: overloads.iter().map(|def| def.to_plank_str()).collect(),
+            line,
+        }),
+    }
+}
+
+/// Lists and records don't have one way to be written out, so they can't be
+/// put in a string.
+fn get_interpolated_string_errors(value: &Value, line: u32) -> Option<SemanticError> {
+    value
+        .params
+        .iter()
+        .find(|piece| {
+            !matches!(
+                piece.data_type,
+                DataType::Number | DataType::String | DataType::Boolean | DataType::Enum(_)
+            )
+        })
+        .map(|piece| SemanticError::InterpolatedValueInvalid {
+            found: piece.data_type.clone(),
+            line,
+        })
+}
+
+fn get_list_literal_errors(value: &Value, line: u32) -> Option<SemanticError> {
+    let Some(first_element) = value.params.first() else {
+        return Some(SemanticError::ListLiteralEmpty { line });
+    };
+
+    for element in value.params.iter().skip(1) {
+        if element.data_type != first_element.data_type {
+            return Some(SemanticError::ListElementTypeMismatch {
+                expected: first_element.data_type.clone(),
+                found: element.data_type.clone(),
+                line,
+            });
+        }
+    }
+
+    None
+}
+
+/// Checks every step of ps[i].x (also used for the left side of an assignment).
+/// var_name is the variable the access starts at.
+pub fn get_accessor_errors(
+    var_name: &str,
+    accessors: &[Accessor],
+    function_table: &FunctionTable,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
+    // What's been accessed so far, for error messages.
+    let mut path = var_name.to_string();
+
+    for accessor in accessors {
+        match &accessor.kind {
+            AccessorKind::Index(index) => {
+                if let Some(error) =
+                    get_operation_errors_logical(index, function_table, type_table, line)
+                {
+                    return Some(error);
+                }
+                if index.data_type != DataType::Number {
+                    return Some(SemanticError::TypeMismatch {
+                        expected: DataType::Number,
+                        found: index.data_type.clone(),
+                        line,
+                    });
+                }
+                // Type resolution makes this invalid when there's no list to index into.
+                if accessor.data_type == DataType::Invalid {
+                    return Some(SemanticError::IndexTargetInvalid { name: path, line });
+                }
+                path.push_str("[...]");
+            }
+            AccessorKind::Field(field_name) => {
+                // Same for a field that isn't in the record (or there's no record).
+                if accessor.data_type == DataType::Invalid {
+                    return Some(SemanticError::FieldNotFound {
+                        name: path,
+                        field: field_name.clone(),
+                        line,
+                    });
+                }
+                path.push('.');
+                path.push_str(field_name);
+            }
+        }
+    }
+
+    None
+}
+
+fn get_enum_variant_errors(value: &Value, line: u32) -> Option<SemanticError> {
+    // Type resolution makes this invalid when the variant doesn't exist.
+    if value.data_type != DataType::Invalid {
+        return None;
+    }
+
+    match value.accessors.as_slice() {
+        [
+            Accessor {
+                kind: AccessorKind::Field(variant),
+                ..
+            },
+        ] => Some(SemanticError::EnumVariantNotFound {
+            enum_name: value.raw_text.clone(),
+            variant: variant.clone(),
+            line,
+        }),
+        // Color[0], Color.Red.x, etc.
+        _ => Some(SemanticError::InvalidValueReference {
+            name: value.raw_text.clone(),
+            line,
+        }),
+    }
+}
+
+fn get_record_construction_errors(
+    value: &Value,
+    type_table: &TypeTable,
+    line: u32,
+) -> Option<SemanticError> {
+    let record = type_table.get_record(&value.raw_text)?;
+
+    // Fields passed by name are put in order in type resolution, unless they
+    // don't match up with the record.
+    if value.argument_names.iter().any(Option::is_some)
+        && let Err(error) =
+            bind_arguments(&record.fields, &value.argument_names, value.params.len())
+    {
+        return Some(get_argument_binding_error(
+            error,
+            &record.identifier,
+            record.fields.len(),
+            value.params.len(),
+            line,
+        ));
+    }
+
+    if value.params.len() != record.fields.len() {
+        return Some(SemanticError::IncorrectParameters {
+            parameters_expected: record.fields.len(),
+            parameters_provided: value.params.len(),
+            line,
+        });
+    }
+
+    // Fields are given in the order they're declared in.
+    for (field, argument) in record.fields.iter().zip(value.params.iter()) {
+        if argument.data_type != field.data_type {
+            return Some(SemanticError::RecordFieldTypeMismatch {
+                record: record.identifier.clone(),
+                field: field.name.clone(),
+                expected: field.data_type.clone(),
+                found: argument.data_type.clone(),
+                line,
+            });
+        }
+    }
+
+    None
+}
+
+fn get_builtin_call_errors(value: &Value, line: u32) -> Option<SemanticError> {
+    let builtin = get_builtin(&value.raw_text)?;
+
+    if value.argument_names.iter().any(Option::is_some) {
+        return Some(SemanticError::BuiltinArgumentNamed {
+            name: value.raw_text.clone(),
+            line,
+        });
+    }
+
+    if value.params.len() != builtin.parameters.len() {
+        return Some(SemanticError::IncorrectParameters {
+            parameters_expected: builtin.parameters.len(),
+            parameters_provided: value.params.len(),
+            line,
+        });
+    }
+
+    for (param, argument) in builtin.parameters.iter().zip(&value.params) {
+        if !param.parameter_type.accepts(&argument.data_type) {
+            let mut expected = param.parameter_type.to_plank_str().to_string();
+            if builtin.parameters.len() > 1 {
+                expected.push_str(&format!(" for '{}'", param.name));
+            }
+            return Some(SemanticError::BuiltinArgumentInvalid {
+                name: value.raw_text.clone(),
+                expected,
+                found: argument.data_type.clone(),
+                line,
+            });
+        }
+    }
+
     None
 }
@@ -6,11 +6,17 @@ pub struct VariableSymbol {
     pub identifier: String,
     pub data_type: DataType,
     pub line_declared_on: u32,
+    /// Declared with const, so it can't be assigned to.
+    pub is_const: bool,
+    /// Declared at the top level of the file, so it's a c global.
+    pub is_global: bool,
 }
 
 /// A wrapper around HashMap for managing symbol identifiers in the compiler.
 /// Automatically assigns unique u8 keys when symbols are inserted and maintains
 /// ownership of all symbol strings in one centralized location.
+/// Every scope has its own table, which owns the table of the scope around it,
+/// so anything declared further out can be looked up through it.
 #[derive(Debug)]
 pub struct SymbolTable {
     // This setup is gross. Created symbols map first, then needed to be able to
@@ -19,6 +25,10 @@ pub struct SymbolTable {
     name_to_key: HashMap<String, u8>,
     // Counter to generate unique keys for new symbols
     next_key: u8,
+    parent: Option<Box<SymbolTable>>,
+    /// The outermost scope of a function's body. Its variables (and the ones
+    /// in blocks inside of it) can have the same name as a global.
+    is_function_scope: bool,
 }
 
 impl SymbolTable {
@@ -28,9 +38,25 @@ impl SymbolTable {
             symbols: HashMap::new(),
             name_to_key: HashMap::new(),
             next_key: 0,
+            parent: None,
+            is_function_scope: false,
         }
     }
 
+    /// Creates an empty table for a scope inside of the parent's scope.
+    pub fn new_child(parent: SymbolTable, is_function_scope: bool) -> Self {
+        Self {
+            parent: Some(Box::new(parent)),
+            is_function_scope,
+            ..Self::new()
+        }
+    }
+
+    /// Gives back the table of the scope around this one, once this scope ends.
+    pub fn into_parent(self) -> Option<SymbolTable> {
+        self.parent.map(|parent| *parent)
+    }
+
     /// Inserts a new symbol into the table and returns the assigned key.
     /// Returns None if the table is full (reached u8::MAX symbols) or if a symbol
     /// with the same name already exists.
@@ -39,6 +65,8 @@ impl SymbolTable {
         symbol_name: &String,
         data_type: &DataType,
         line_declared_on: &u32,
+        is_const: bool,
+        is_global: bool,
     ) -> Option<u8> {
         // Check for duplicate names
         if self.name_to_key.contains_key(symbol_name) {
@@ -57,6 +85,8 @@ impl SymbolTable {
                 identifier: symbol_name.clone(),
                 data_type: data_type.clone(),
                 line_declared_on: *line_declared_on,
+                is_const,
+                is_global,
             },
         );
         self.name_to_key.insert(symbol_name.clone(), key);
@@ -72,16 +102,26 @@ impl SymbolTable {
         self.symbols.get(&key)
     }
 
+    /// Looks the name up in this scope, then in every scope around it.
     pub fn get(&self, symbol_name: &str) -> Option<&VariableSymbol> {
-        if !self.contains_name(symbol_name) {
-            return None;
+        if let Some(symbol_id) = self.find_by_name(symbol_name) {
+            return self.get_using_id(symbol_id);
         }
 
+        self.parent.as_ref()?.get(symbol_name)
+    }
+
+    /// Same as get, but stops at the scope of the function this is in, since a
+    /// function's variables are allowed to hide globals.
+    pub fn get_in_current_function(&self, symbol_name: &str) -> Option<&VariableSymbol> {
         if let Some(symbol_id) = self.find_by_name(symbol_name) {
             return self.get_using_id(symbol_id);
         }
+        if self.is_function_scope {
+            return None;
+        }
 
-        None
+        self.parent.as_ref()?.get_in_current_function(symbol_name)
     }
 
     pub fn get_id_with_symbol_name(&self, symbol_name: &str) -> Option<u8> {
@@ -108,7 +148,7 @@ impl SymbolTable {
         self.name_to_key.get(name).copied()
     }
 
-    /// Checks if a variable with the given name has been declared
+    /// Checks if a variable with the given name has been declared in this scope
     pub fn contains_name(&self, name: &str) -> bool {
         self.name_to_key.contains_key(name)
     }
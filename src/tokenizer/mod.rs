@@ -54,10 +54,14 @@ fn get_char_token_map() -> HashMap<char, TokenMatch> {
 
     [
         ('+', Single(Plus)),
-        ('*', Single(Asterisk)),
+        ('*', Double('*', DoubleAsterisk)),
+        ('%', Single(Percent)),
+        ('.', Double('.', DotDot)),
         (':', Single(Colon)),
         ('(', Single(LeftParen)),
         (')', Single(RightParen)),
+        ('[', Single(LeftBracket)),
+        (']', Single(RightBracket)),
         (',', Single(Comma)),
         (';', Single(Semicolon)),
         ('-', Double('>', Arrow)),
@@ -76,6 +80,8 @@ fn get_char_token_map() -> HashMap<char, TokenMatch> {
 fn get_single_char_fallback(ch: char) -> TokenType {
     match ch {
         '-' => TokenType::Minus,
+        '*' => TokenType::Asterisk,
+        '.' => TokenType::Dot,
         '=' => TokenType::Equal,
         '<' => TokenType::LessThan,
         '>' => TokenType::GreaterThan,
@@ -313,6 +319,14 @@ fn create_token_at_byte_in_line(
     }
 }
 
+/// Tokenizes plank code that's inside of something else (ex. the {i + 1} in an
+/// interpolated string), ending it with an EOF like a file.
+pub fn tokenize_str(text: &str, line_number: u32) -> Vec<Token> {
+    let mut tokens = tokenize_line(text.to_string(), line_number);
+    tokens.push(create_token(TokenType::EOF, String::new(), line_number, 0));
+    tokens
+}
+
 fn tokenize_line(line: String, line_number: u32) -> Vec<Token> {
     let mut tokens: Vec<Token> = Vec::new();
     let line_bytes: &[u8] = line.as_bytes();
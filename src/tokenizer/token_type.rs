@@ -20,19 +20,39 @@ pub enum TokenType {
     Goto,
     Print,
     Println,
+    Eprint,
+    Eprintln,
     Input,
     Let,
+    Const,
     If,
     Then,
     Else,
+    ElseIf,
     EndIf,
     While,
     Do,
     EndWhile,
+    For,
+    In,
+    Step,
+    EndFor,
+    Break,
+    Continue,
+    Match,
+    Case,
+    Default,
+    EndMatch,
     Return,
     Returns, // used in function declarations
+    Record,
+    EndRecord,
+    Enum,
+    EndEnum,
     LeftParen,
     RightParen,
+    LeftBracket,
+    RightBracket,
     Comma,
     Arrow,
     EndFunction,
@@ -53,6 +73,10 @@ pub enum TokenType {
     Bang,
     Colon,
     Semicolon,
+    Percent,
+    DoubleAsterisk,
+    Dot,
+    DotDot,
     UnsupportedSymbolError = 900,
     // Won't get through to the parser, just for processing in here.
     Space,
@@ -71,26 +95,45 @@ impl FromStr for TokenType {
             "goto" => Ok(TokenType::Goto),
             "print" => Ok(TokenType::Print),
             "println" => Ok(TokenType::Println),
+            "eprint" => Ok(TokenType::Eprint),
+            "eprintln" => Ok(TokenType::Eprintln),
             "input" => Ok(TokenType::Input),
             "let" => Ok(TokenType::Let),
+            "const" => Ok(TokenType::Const),
             "if" => Ok(TokenType::If),
             "then" => Ok(TokenType::Then),
             "endIf" => Ok(TokenType::EndIf),
             "while" => Ok(TokenType::While),
             "do" => Ok(TokenType::Do),
             "endWhile" => Ok(TokenType::EndWhile),
-            "Number" | "String" | "Boolean" => Ok(TokenType::VarDeclaration),
+            "for" => Ok(TokenType::For),
+            "in" => Ok(TokenType::In),
+            "step" => Ok(TokenType::Step),
+            "endFor" => Ok(TokenType::EndFor),
+            "break" => Ok(TokenType::Break),
+            "continue" => Ok(TokenType::Continue),
+            "match" => Ok(TokenType::Match),
+            "case" => Ok(TokenType::Case),
+            "default" => Ok(TokenType::Default),
+            "endMatch" => Ok(TokenType::EndMatch),
+            "Number" | "String" | "Boolean" | "List" => Ok(TokenType::VarDeclaration),
             "update" => Ok(TokenType::UpdateKeyword),
             "function" => Ok(TokenType::FunctionDeclaration),
             "return" => Ok(TokenType::Return),
             "returns" => Ok(TokenType::Returns),
             "endFunction" => Ok(TokenType::EndFunction),
+            "record" => Ok(TokenType::Record),
+            "endRecord" => Ok(TokenType::EndRecord),
+            "enum" => Ok(TokenType::Enum),
+            "endEnum" => Ok(TokenType::EndEnum),
             "else" => Ok(TokenType::Else),
+            "elseIf" => Ok(TokenType::ElseIf),
             "Boolean" => Ok(TokenType::Boolean),
             "true" => Ok(TokenType::True),
             "false" => Ok(TokenType::False),
             "and" => Ok(TokenType::DoubleAmpersand),
             "or" => Ok(TokenType::DoubleBar),
+            "not" => Ok(TokenType::Bang),
             _ => Err(()),
         }
     }
@@ -115,20 +158,38 @@ impl TokenType {
             TokenType::Goto => "goto",
             TokenType::Print => "print",
             TokenType::Println => "println",
+            TokenType::Eprint => "eprint",
+            TokenType::Eprintln => "eprintln",
             TokenType::Input => "input",
             TokenType::Let => "let",
+            TokenType::Const => "const",
             TokenType::If => "if",
             TokenType::Then => "then",
             TokenType::Else => "else",
+            TokenType::ElseIf => "elseIf",
             TokenType::EndIf => "endIf",
             TokenType::While => "while",
             TokenType::Do => "do",
             TokenType::EndWhile => "endWhile",
+            TokenType::For => "for",
+            TokenType::In => "in",
+            TokenType::Step => "step",
+            TokenType::EndFor => "endFor",
+            TokenType::Break => "break",
+            TokenType::Continue => "continue",
+            TokenType::Match => "match",
+            TokenType::Case => "case",
+            TokenType::Default => "default",
+            TokenType::EndMatch => "endMatch",
             // Operators
             TokenType::Equal => "=",
             TokenType::Plus => "+",
             TokenType::Minus => "-",
             TokenType::Asterisk => "*",
+            TokenType::DoubleAsterisk => "**",
+            TokenType::Percent => "%",
+            TokenType::Dot => ".",
+            TokenType::DotDot => "..",
             TokenType::Slash => "/",
             TokenType::EqualEqual => "==",
             TokenType::NotEqual => "!=",
@@ -146,9 +207,15 @@ impl TokenType {
             TokenType::Returns => "returns",
             TokenType::LeftParen => "(",
             TokenType::RightParen => ")",
+            TokenType::LeftBracket => "[",
+            TokenType::RightBracket => "]",
             TokenType::Comma => ",",
             TokenType::Arrow => "->",
             TokenType::EndFunction => "endFunction",
+            TokenType::Record => "record",
+            TokenType::EndRecord => "endRecord",
+            TokenType::Enum => "enum",
+            TokenType::EndEnum => "endEnum",
             TokenType::Semicolon => ";",
             TokenType::Boolean => "bool",
             TokenType::True => "true",
@@ -0,0 +1,117 @@
+// Helpers for the behavior tests: each test writes a plank program, compiles it
+// with the plank compiler and then gcc, and checks what the program did.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_DIR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// What a compiled plank program did when it ran.
+pub struct RunOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub status: i32,
+}
+
+/// Every test gets its own directory, since tests run in parallel.
+fn new_test_dir() -> PathBuf {
+    let id = NEXT_DIR_ID.fetch_add(1, Ordering::SeqCst);
+    let dir = std::env::temp_dir().join(format!("plank_test_{}_{}", std::process::id(), id));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs the plank compiler, and returns the directory main.c would be put in
+/// along with everything the compiler printed.
+fn invoke_compiler(source: &str, flags: &[&str]) -> (PathBuf, String) {
+    let dir = new_test_dir();
+    let src_path = dir.join("main.plank");
+    fs::write(&src_path, source).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_compiler"))
+        .arg(&src_path)
+        .arg(&dir)
+        .args(flags)
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    let printed = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    (dir, printed)
+}
+
+/// The directory main.c was put in, or what the compiler printed if it didn't
+/// make one (the compile failed).
+fn compile_plank(source: &str, flags: &[&str]) -> Result<PathBuf, String> {
+    let (dir, printed) = invoke_compiler(source, flags);
+    if dir.join("main.c").exists() {
+        Ok(dir)
+    } else {
+        let _ = fs::remove_dir_all(&dir);
+        Err(printed)
+    }
+}
+
+/// Compiles and runs a plank program that's expected to compile.
+pub fn run(source: &str) -> RunOutput {
+    run_with_flags(source, &[])
+}
+
+pub fn run_with_flags(source: &str, flags: &[&str]) -> RunOutput {
+    let dir = match compile_plank(source, flags) {
+        Ok(dir) => dir,
+        Err(errors) => panic!("plank program failed to compile:\n{}", errors),
+    };
+
+    let exe_path = dir.join("plank_program");
+    let gcc = Command::new("gcc")
+        .arg(dir.join("main.c"))
+        .arg("-o")
+        .arg(&exe_path)
+        .arg("-Werror=format")
+        .output()
+        .unwrap();
+    assert!(
+        gcc.status.success(),
+        "gcc failed on the generated c:\n{}",
+        String::from_utf8_lossy(&gcc.stderr)
+    );
+
+    let output = Command::new(&exe_path).output().unwrap();
+    let _ = fs::remove_dir_all(&dir);
+    RunOutput {
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        status: output.status.code().unwrap_or(-1),
+    }
+}
+
+/// Compiles a plank program that's expected to be rejected, and returns what
+/// the compiler printed.
+pub fn compile_errors(source: &str) -> String {
+    compile_errors_with_flags(source, &[])
+}
+
+pub fn compile_errors_with_flags(source: &str, flags: &[&str]) -> String {
+    match compile_plank(source, flags) {
+        Ok(dir) => {
+            let _ = fs::remove_dir_all(&dir);
+            panic!("plank program was expected to fail to compile");
+        }
+        Err(errors) => errors,
+    }
+}
+
+/// What the compiler printed for a program that compiles (ex. warnings).
+pub fn compile_output(source: &str) -> String {
+    let (dir, printed) = invoke_compiler(source, &[]);
+    let _ = fs::remove_dir_all(&dir);
+    printed
+}
@@ -0,0 +1,11 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn else_if_chains_take_one_arm() {
+    let output = run(
+        "for n in 0..4:\n    if (n == 0):\n        println(\"zero\");\n    elseIf (n == 1):\n        println(\"one\");\n    elseIf (n == 2):\n        println(\"two\");\n    else:\n        println(\"many\");\n    endIf\nendFor\n",
+    );
+    assert_eq!(output.stdout, "zero\none\ntwo\nmany\n");
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::{compile_errors, run};
+
+#[test]
+fn constants_can_be_used_in_functions_declared_above_them() {
+    let output = run(
+        "function canRetry (Number tries) returns Boolean:\n    return tries < MAX_TRIES;\nendFunction\nconst Number MAX_TRIES: 3;\nconst String NAME: \"retry\";\nprintln(NAME, canRetry(2), canRetry(3));\n",
+    );
+    assert_eq!(output.stdout, "retry true false\n");
+}
+
+#[test]
+fn constants_cant_be_assigned_or_set_at_runtime() {
+    let errors =
+        compile_errors("const Number MAX: 3;\nMAX <= 4;\nNumber k: 1;\nconst Number z: k;\n");
+    assert!(
+        errors.contains("'MAX' is a constant, so it can't be assigned to"),
+        "{}",
+        errors
+    );
+    assert!(
+        errors.contains("Constant 'z' has to be set to a literal"),
+        "{}",
+        errors
+    );
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use common::{compile_errors, run};
+
+#[test]
+fn values_convert_to_and_from_strings() {
+    let output = run(
+        "enum Color: Red, Green endEnum\nString n: toString(12);\nprintln(toNumber(\"41\") + 1, n, toString(true), toString(Color.Green), toBoolean(\"false\"));\n",
+    );
+    assert_eq!(output.stdout, "42 12 true Green false\n");
+}
+
+#[test]
+fn bad_conversions_are_errors() {
+    let output = run("println(toNumber(\"7\"));\nprintln(toNumber(\"4x\"));\n");
+    assert_eq!(output.stdout, "7\n");
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: can't turn \"4x\" into a Number (line 2)\n"
+    );
+    assert_eq!(output.status, 1);
+
+    let errors = compile_errors("println(toNumber(3));\n");
+    assert!(errors.contains("toNumber expects a String"), "{}", errors);
+}
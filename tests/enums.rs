@@ -0,0 +1,91 @@
+mod common;
+
+use common::{compile_errors, compile_output, run};
+
+const COLOR: &str = "enum Color: Red, Green, Blue endEnum\n";
+
+#[test]
+fn match_on_an_enum() {
+    let output = run(&format!(
+        "{}Color c: Color.Blue;\nmatch (c):\n    case Color.Red:\n        println(\"red\");\n    case Color.Green, Color.Blue:\n        println(\"not red\");\nendMatch\n",
+        COLOR
+    ));
+    assert_eq!(output.stdout, "not red\n");
+}
+
+#[test]
+fn match_on_an_enum_with_a_default() {
+    let output = run(&format!(
+        "{}Color c: Color.Green;\nmatch (c):\n    case Color.Red:\n        println(\"red\");\n    default:\n        println(c);\nendMatch\n",
+        COLOR
+    ));
+    assert_eq!(output.stdout, "Green\n");
+}
+
+#[test]
+fn match_handling_every_variant_has_no_warning() {
+    let printed = compile_output(&format!(
+        "{}Color c: Color.Red;\nmatch (c):\n    case Color.Red, Color.Green:\n        println(1);\n    case Color.Blue:\n        println(2);\nendMatch\n",
+        COLOR
+    ));
+    assert!(!printed.contains("Warning"), "{}", printed);
+}
+
+#[test]
+fn match_warns_about_unhandled_variants() {
+    let printed = compile_output(&format!(
+        "{}Color c: Color.Red;\nmatch (c):\n    case Color.Red:\n        println(1);\nendMatch\n",
+        COLOR
+    ));
+    assert!(
+        printed.contains("This match over Color doesn't handle: Green, Blue"),
+        "{}",
+        printed
+    );
+    assert!(!printed.contains("Match has no default"), "{}", printed);
+}
+
+#[test]
+fn match_cases_must_be_variants_of_the_enum() {
+    let errors = compile_errors(&format!(
+        "{}Color c: Color.Red;\nmatch (c):\n    case Color.Purple:\n        println(1);\n    case 3:\n        println(2);\nendMatch\n",
+        COLOR
+    ));
+    assert!(
+        errors.contains("Enum 'Color' has no variant named 'Purple'"),
+        "{}",
+        errors
+    );
+    assert!(errors.contains("Expected: Color"), "{}", errors);
+}
+
+#[test]
+fn enums_are_declared_at_the_top_level() {
+    let errors = compile_errors(
+        "Number n: 1;\nif (n == 1):\n    enum Shade: Light, Dark endEnum\nendIf\nfunction f () returns Void:\n    enum Size: Small, Big endEnum\nendFunction\nf();\n",
+    );
+    assert!(
+        errors.contains("Enum 'Shade' has to be declared outside of any function or block"),
+        "{}",
+        errors
+    );
+    assert!(
+        errors.contains("Enum 'Size' has to be declared outside of any function or block"),
+        "{}",
+        errors
+    );
+}
+
+#[test]
+fn if_chains_warn_about_unhandled_variants() {
+    let printed = compile_output(&format!(
+        "{}Color c: Color.Red;\nif (c == Color.Red):\n    println(1);\nelseIf (c == Color.Green):\n    println(2);\nendIf\nif (c == Color.Red):\n    println(1);\nelse:\n    println(2);\nendIf\n",
+        COLOR
+    ));
+    assert!(
+        printed.contains("This if chain over Color doesn't handle: Blue"),
+        "{}",
+        printed
+    );
+    assert_eq!(printed.matches("Warning").count(), 1, "{}", printed);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::{compile_errors, compile_output, run};
+
+#[test]
+fn overloads_are_picked_by_parameter_types() {
+    let output = run(
+        "function describe (Number x) returns String:\n    return \"number\";\nendFunction\nfunction describe (String x) returns String:\n    return \"string\";\nendFunction\nfunction describe (Number x, Number y) returns String:\n    return \"two numbers\";\nendFunction\nprintln(describe(1));\nprintln(describe(\"a\"));\nprintln(describe(1, 2));\n",
+    );
+    assert_eq!(output.stdout, "number\nstring\ntwo numbers\n");
+}
+
+#[test]
+fn overloads_cant_differ_only_by_return_type() {
+    let errors = compile_errors(
+        "function f (Number x) returns Number:\n    return x;\nendFunction\nfunction f (Number x) returns String:\n    return \"hi\";\nendFunction\nprintln(f(1));\n",
+    );
+    assert!(errors.contains("1 errors found"), "{}", errors);
+    assert!(
+        errors.contains("Function 'f(Number)' is already declared"),
+        "{}",
+        errors
+    );
+    assert!(!errors.contains("Incorrect return type"), "{}", errors);
+}
+
+#[test]
+fn builtin_names_are_reserved() {
+    let errors = compile_errors(
+        "function max (Number a, Number b) returns Number:\n    return a;\nendFunction\nprintln(max(1, 2));\n",
+    );
+    assert!(
+        errors.contains("max is a builtin function; pick a different name"),
+        "{}",
+        errors
+    );
+    assert!(!errors.contains("Function never called"), "{}", errors);
+}
+
+#[test]
+fn redeclared_overloads_arent_warned_about_twice() {
+    let output = compile_output(
+        "function f (Number x) returns Number:\n    return x;\nendFunction\nfunction f (Number x) returns String:\n    return \"hi\";\nendFunction\n",
+    );
+    assert_eq!(
+        output.matches("Function never called").count(),
+        1,
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn ambiguity_from_defaults_points_at_the_defaults() {
+    let errors = compile_errors(
+        "function f (Number x) returns Number:\n    return x;\nendFunction\nfunction f (Number x, Number y = 2) returns Number:\n    return x + y;\nendFunction\nprintln(f(1));\n",
+    );
+    assert!(
+        errors.contains("Default parameters make these overloads take the same arguments"),
+        "{}",
+        errors
+    );
+}
+
+#[test]
+fn defaults_fill_in_arguments_left_out() {
+    let output = run(
+        "function greet (String name, String greeting = \"hi\", Number times = 1) returns Void:\n    for i in 0..times:\n        println(greeting, name);\n    endFor\nendFunction\ngreet(\"bob\");\ngreet(times: 2, name: \"amy\", greeting: \"yo\");\ngreet(\"sam\", greeting: \"hey\");\n",
+    );
+    assert_eq!(output.stdout, "hi bob\nyo amy\nyo amy\nhey sam\n");
+}
+
+#[test]
+fn named_arguments_must_match_the_parameters() {
+    let errors = compile_errors(
+        "function greet (String name, String greeting = \"hi\") returns Void:\n    println(greeting, name);\nendFunction\ngreet(greeting: \"x\");\ngreet(\"a\", name: \"b\");\n",
+    );
+    assert!(
+        errors.contains("Nothing is passed to parameter 'name' of 'greet', which has no default"),
+        "{}",
+        errors
+    );
+    assert!(
+        errors.contains("Parameter 'name' of 'greet' is passed more than one argument"),
+        "{}",
+        errors
+    );
+}
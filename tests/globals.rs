@@ -0,0 +1,70 @@
+mod common;
+
+use common::{compile_errors, run};
+
+#[test]
+fn globals_can_share_names_with_c_functions() {
+    let output = run(
+        "Number index: 1;\nNumber exit: 2;\nconst Number abs: 3;\nprintln(index + exit + abs);\n",
+    );
+    assert_eq!(output.stdout, "6\n");
+    assert_eq!(output.status, 0);
+}
+
+#[test]
+fn globals_are_used_and_assigned_in_functions() {
+    let output = run(
+        "Number index: 1;\nList<Number> printf: [4, 5];\nfunction bump () returns Void:\n    printf[0] <= printf[0] + index;\n    index <= 10;\nendFunction\nbump();\nprintln(\"{index} {printf[0]}\");\n",
+    );
+    assert_eq!(output.stdout, "10 5\n");
+}
+
+#[test]
+fn locals_can_hide_globals() {
+    let output = run(
+        "Number i: 10;\nfunction f () returns Number:\n    Number i: 3;\n    if (i == 3):\n        i <= i + 1;\n    endIf\n    return i;\nendFunction\nfunction twice (Number i) returns Number:\n    return i * 2;\nendFunction\nprintln(f());\nprintln(twice(7));\nprintln(i);\n",
+    );
+    assert_eq!(output.stdout, "4\n14\n10\n");
+}
+
+#[test]
+fn blocks_cant_redeclare_variables() {
+    let errors = compile_errors(
+        "Number i: 10;\nif (i == 10):\n    Number i: 2;\nendIf\nfunction f () returns Number:\n    Number k: 3;\n    if (k == 3):\n        Number k: 1;\n    endIf\n    return k;\nendFunction\nprintln(f());\n",
+    );
+    assert!(
+        errors.contains("Variable 'i' is already declared"),
+        "{}",
+        errors
+    );
+    assert!(
+        errors.contains("Variable 'k' is already declared"),
+        "{}",
+        errors
+    );
+}
+
+#[test]
+fn function_variables_named_like_a_later_global_are_fine() {
+    let output = run(
+        "println(f());\nNumber late: 2;\nNumber i: 5;\nfunction f () returns Number:\n    Number late: 7;\n    for i in 0..3:\n        late <= late + i;\n    endFor\n    return late;\nendFunction\nprintln(late + i);\n",
+    );
+    assert_eq!(output.stdout, "10\n7\n");
+}
+
+#[test]
+fn globals_must_be_set_before_a_function_uses_them() {
+    let output = run(
+        "Number g: 2; println(f());\nfunction f () returns Number:\n    return g;\nendFunction\n",
+    );
+    assert_eq!(output.stdout, "2\n");
+
+    let errors = compile_errors(
+        "println(f()); Number g: 2;\nfunction f () returns Number:\n    return g;\nendFunction\n",
+    );
+    assert!(
+        errors.contains("Calling 'f' here uses the global 'g', which isn't set yet"),
+        "{}",
+        errors
+    );
+}
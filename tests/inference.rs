@@ -0,0 +1,21 @@
+mod common;
+
+use common::{compile_errors, run};
+
+#[test]
+fn let_takes_the_type_of_its_value() {
+    let output = run(
+        "let total: 0;\nlet names: [\"ada\", \"bo\"];\nconst let MAX: 3;\ntotal <= total + length(names) + MAX;\nlet big: total > 2;\nprintln(total, names, big);\n",
+    );
+    assert_eq!(output.stdout, "5 [ada, bo] true\n");
+}
+
+#[test]
+fn let_variables_keep_their_type() {
+    let errors = compile_errors("let total: 0;\nprintln(total);\ntotal <= \"x\";\n");
+    assert!(
+        errors.contains("Expected: Number") && errors.contains("Found:    String"),
+        "{}",
+        errors
+    );
+}
@@ -0,0 +1,23 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn number_literals_can_be_printed() {
+    let output = run("println(5);\nprintln(\"{5}\");\n");
+    assert_eq!(output.stdout, "5\n5\n");
+}
+
+#[test]
+fn values_are_put_in_the_string() {
+    let output = run(
+        "Number n: 7;\nBoolean b: true;\nString name: \"plank\";\nprintln(\"{name}: {n + 1} {b}\");\nString s: \"n is {n}\";\nprintln(s);\n",
+    );
+    assert_eq!(output.stdout, "plank: 8 true\nn is 7\n");
+}
+
+#[test]
+fn braces_and_percent_are_escaped() {
+    let output = run("Number n: 50;\nprintln(\"{n}% {{literal}} 100%d\");\n");
+    assert_eq!(output.stdout, "50% {literal} 100%d\n");
+}
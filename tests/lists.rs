@@ -0,0 +1,22 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn lists_are_indexed_assigned_and_shared() {
+    let output = run(
+        "List<Number> scores: [90, 72, 85];\nscores[1] <= scores[1] + 10;\nList<Number> same: scores;\nsame[2] <= 0;\nprintln(scores);\nprintln(length(scores));\nList<List<String>> grid: [[\"a\", \"b\"], [\"c\"]];\nprintln(grid[0][1], length(grid[1]));\n",
+    );
+    assert_eq!(output.stdout, "[90, 82, 0]\n3\nb 1\n");
+}
+
+#[test]
+fn index_out_of_bounds_is_a_runtime_error() {
+    let output = run("List<Number> xs: [1, 2];\nprintln(xs[0]);\nprintln(xs[2]);\n");
+    assert_eq!(output.stdout, "1\n");
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: list index 2 is out of bounds (length 2) (line 3)\n"
+    );
+    assert_eq!(output.status, 1);
+}
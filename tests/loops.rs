@@ -0,0 +1,74 @@
+mod common;
+
+use common::{compile_errors, run, run_with_flags};
+
+#[test]
+fn for_loop_steps_up_and_down() {
+    let output = run(
+        "for i in 0..3:\n    print(i);\nendFor\nprintln();\nfor i in 10..0 step -4:\n    print(i);\nendFor\nprintln();\n",
+    );
+    assert_eq!(output.stdout, "012\n1062\n");
+}
+
+#[test]
+fn for_loop_step_stops_at_the_end_of_the_range() {
+    let source = "for k in 9223372036854775800..9223372036854775807 step 5:\n    println(k);\nendFor\n\
+                  for k in -9223372036854775800..-9223372036854775807 step -5:\n    println(k);\nendFor\n";
+    let expected = "9223372036854775800\n9223372036854775805\n\
+                    -9223372036854775800\n-9223372036854775805\n";
+
+    let output = run(source);
+    assert_eq!(output.stdout, expected);
+    assert_eq!(output.status, 0);
+
+    // Without runtime checks, the step used to wrap around and loop forever.
+    let output = run_with_flags(source, &["--no-runtime-checks"]);
+    assert_eq!(output.stdout, expected);
+    assert_eq!(output.status, 0);
+}
+
+#[test]
+fn for_loop_step_of_zero_is_a_runtime_error() {
+    let output = run("Number by: 0;\nfor i in 0..3 step by:\n    println(i);\nendFor\n");
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: for loop step can't be 0 (line 2)\n"
+    );
+    assert_eq!(output.status, 1);
+}
+
+#[test]
+fn return_inside_of_a_loop_in_a_function() {
+    let output = run(
+        "function firstOver(Number limit) returns Number:\n    for i in 0..100 step 7:\n        if (i > limit):\n            return i;\n        endIf\n    endFor\n    return -1;\nendFunction\nprintln(firstOver(20));\n",
+    );
+    assert_eq!(output.stdout, "21\n");
+}
+
+#[test]
+fn return_outside_of_a_function_is_an_error() {
+    let errors = compile_errors("if (true):\n    return 1;\nendIf\n");
+    assert!(
+        errors.contains("return can only be used inside of a function"),
+        "{}",
+        errors
+    );
+}
+
+#[test]
+fn break_and_continue_leave_the_loop_or_skip_ahead() {
+    let output = run(
+        "Number i: 0;\nwhile (i < 10):\n    i <= i + 1;\n    if (i == 2):\n        continue;\n    endIf\n    if (i == 5):\n        break;\n    endIf\n    print(i);\nendWhile\nprintln();\nfor k in 0..5:\n    match (k):\n        case 3:\n            break;\n        default:\n            print(k);\n    endMatch\nendFor\nprintln();\n",
+    );
+    assert_eq!(output.stdout, "134\n012\n");
+}
+
+#[test]
+fn continue_outside_of_a_loop_is_an_error() {
+    let errors = compile_errors("continue;\n");
+    assert!(
+        errors.contains("'continue' can only be used inside of a while or for loop"),
+        "{}",
+        errors
+    );
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::{compile_output, run};
+
+#[test]
+fn match_on_numbers_and_strings() {
+    let output = run(
+        "for n in 0..4:\n    match (n):\n        case 0:\n            print(\"zero \");\n        case 1, 2:\n            print(\"small \");\n        default:\n            print(\"big \");\n    endMatch\nendFor\nprintln();\nString name: \"bob\";\nmatch (name):\n    case \"alice\", \"bob\":\n        println(\"known\");\n    default:\n        println(\"stranger\");\nendMatch\n",
+    );
+    assert_eq!(output.stdout, "zero small small big \nknown\n");
+}
+
+#[test]
+fn match_warns_about_duplicate_cases_and_a_missing_default() {
+    let printed = compile_output(
+        "Number n: 1;\nmatch (n):\n    case 1:\n        println(1);\n    case 2, 1:\n        println(2);\nendMatch\n",
+    );
+    assert!(
+        printed.contains("Case 1 was already handled on line 3"),
+        "{}",
+        printed
+    );
+    assert!(
+        printed.contains("Match has no default (line 2)"),
+        "{}",
+        printed
+    );
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::{run, run_with_flags};
+
+#[test]
+fn numbers_are_64_bit() {
+    let output = run("Number big: 4000000000;\nprintln(big * 2);\n");
+    assert_eq!(output.stdout, "8000000000\n");
+}
+
+#[test]
+fn overflow_is_a_runtime_error() {
+    let output = run("Number big: 9223372036854775807;\nprintln(\"before\");\nprintln(big + 1);\n");
+    assert_eq!(output.stdout, "before\n");
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: integer overflow in addition (line 3)\n"
+    );
+    assert_eq!(output.status, 1);
+}
+
+#[test]
+fn division_by_zero_is_a_runtime_error() {
+    let output = run("Number zero: 0;\nprintln(10 / zero);\n");
+    assert_eq!(output.stderr, "Runtime Error: division by zero (line 2)\n");
+    assert_eq!(output.status, 1);
+}
+
+#[test]
+fn overflow_wraps_without_runtime_checks() {
+    let output = run_with_flags(
+        "Number big: 9223372036854775807;\nprintln(big + 1);\n",
+        &["--no-runtime-checks"],
+    );
+    assert_eq!(output.stdout, "-9223372036854775808\n");
+    assert_eq!(output.status, 0);
+}
+
+#[test]
+fn dividing_the_smallest_number_wraps_without_runtime_checks() {
+    let source = "Number smallest: -9223372036854775807 - 1;\nNumber by: -1;\nprintln(smallest / by);\nprintln(smallest % by);\n";
+    let output = run_with_flags(source, &["--no-runtime-checks"]);
+    assert_eq!(output.stdout, "-9223372036854775808\n0\n");
+    assert_eq!(output.status, 0);
+
+    let output = run(source);
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: integer overflow in division (line 3)\n"
+    );
+    assert_eq!(output.status, 1);
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::{compile_errors, run};
+
+#[test]
+fn power_is_right_associative() {
+    let output = run("println(2 ** 3 ** 2);\nprintln(-2 ** 2);\n");
+    assert_eq!(output.stdout, "512\n-4\n");
+}
+
+#[test]
+fn exponent_can_be_negated() {
+    let output = run("Number x: 2;\nprintln(2 ** -x);\n");
+    assert_eq!(output.stderr, "Runtime Error: negative exponent (line 2)\n");
+    assert_eq!(output.status, 1);
+
+    let output = run("println(2 ** -1);\n");
+    assert_eq!(output.stderr, "Runtime Error: negative exponent (line 1)\n");
+}
+
+#[test]
+fn negated_exponent_keeps_the_rest_of_the_chain() {
+    let output = run("Number x: -2;\nprintln(3 ** -x ** 1);\nprintln(2 ** -x * 3);\n");
+    assert_eq!(output.stdout, "9\n12\n");
+}
+
+#[test]
+fn prefix_operators_can_be_repeated() {
+    let output = run(
+        "Number a: 5;\nBoolean x: true;\nprintln(- -a);\nprintln(not not x);\nprintln(!not x);\nprintln(- - -a ** 2);\n",
+    );
+    assert_eq!(output.stdout, "5\ntrue\ntrue\n-25\n");
+}
+
+#[test]
+fn repeated_prefix_operators_are_type_checked() {
+    let errors = compile_errors("Boolean x: true;\nprintln(- not x);\n");
+    assert!(
+        errors.contains("Operator - can't be used on a Boolean"),
+        "{}",
+        errors
+    );
+}
+
+#[test]
+fn operators_name_themselves_when_used_on_the_wrong_type() {
+    let errors = compile_errors(
+        "String s: \"a\";\nprintln(s + s);\nNumber n: 1;\nprintln(n == 1 and n);\nprintln(n or true);\n",
+    );
+    for message in [
+        "Operator + can't be used on a String",
+        "Operator and can't be used on a Number",
+        "Operator or can't be used on a Number",
+    ] {
+        assert!(errors.contains(message), "{}", errors);
+    }
+}
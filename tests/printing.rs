@@ -0,0 +1,60 @@
+mod common;
+
+use common::{compile_errors, run};
+
+#[test]
+fn print_separates_values_with_spaces() {
+    let output = run("print(\"a\", \"b\");\nprintln();\nprintln(1, true, \"c\");\n");
+    assert_eq!(output.stdout, "a b\n1 true c\n");
+}
+
+#[test]
+fn separator_changes_what_goes_between_values() {
+    let output =
+        run("println(1, 2, 3, separator: \", \");\nprintln(\"x\", \"y\", separator: \"\");\n");
+    assert_eq!(output.stdout, "1, 2, 3\nxy\n");
+}
+
+#[test]
+fn eprintln_writes_to_stderr() {
+    let output = run("println(\"out\");\neprintln(\"err\", 2);\n");
+    assert_eq!(output.stdout, "out\n");
+    assert_eq!(output.stderr, "err 2\n");
+    assert_eq!(output.status, 0);
+}
+
+#[test]
+fn exit_stops_with_the_code() {
+    let output = run("println(\"before\");\nexit(3);\nprintln(\"after\");\n");
+    assert_eq!(output.stdout, "before\n");
+    assert_eq!(output.status, 3);
+}
+
+#[test]
+fn exit_code_must_be_in_range() {
+    let output = run("exit(300);\n");
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: exit code 300 is not between 0 and 255 (line 1)\n"
+    );
+    assert_eq!(output.status, 1);
+
+    let output = run("Number code: -1;\nexit(code);\n");
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: exit code -1 is not between 0 and 255 (line 2)\n"
+    );
+    assert_eq!(output.status, 1);
+}
+
+#[test]
+fn separator_must_come_after_the_values() {
+    let errors = compile_errors("println(separator: \"a\", 1);\n");
+    assert!(
+        errors.contains(
+            "Argument 2 of 'println' has no name, but comes after an argument passed by name"
+        ),
+        "{}",
+        errors
+    );
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use common::{compile_errors, run};
+
+#[test]
+fn records_print_with_their_fields() {
+    let output = run("record Point:\n    Number x;\n    Number y;\nendRecord\n\
+         record Line:\n    Point start;\n    Point end;\n    List<String> tags;\nendRecord\n\
+         Line l: Line(Point(1, 2), Point(y: 4, x: 3), [\"a\", \"b\"]);\n\
+         l.end.x <= l.start.x + 10;\nprintln(l);\n");
+    assert_eq!(
+        output.stdout,
+        "Line(start: Point(x: 1, y: 2), end: Point(x: 11, y: 4), tags: [a, b])\n"
+    );
+}
+
+#[test]
+fn records_are_declared_at_the_top_level() {
+    let errors = compile_errors(
+        "Number n: 1;\nif (n == 1):\n    record Point:\n        Number x;\n    endRecord\nendIf\nfunction f () returns Void:\n    record Other:\n        Number y;\n    endRecord\nendFunction\nf();\n",
+    );
+    assert!(
+        errors.contains("Record 'Point' has to be declared outside of any function or block"),
+        "{}",
+        errors
+    );
+    assert!(
+        errors.contains("Record 'Other' has to be declared outside of any function or block"),
+        "{}",
+        errors
+    );
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::{compile_errors_with_flags, run_with_flags};
+
+const SUM: &str = "function sum (Number result) returns Number:\n    if (result == 0):\n        return 0;\n    endIf\n    return result + sum(result - 1);\nendFunction\n";
+
+#[test]
+fn recursion_under_the_limit_runs() {
+    let output = run_with_flags(
+        &format!("{}println(sum(10));\n", SUM),
+        &["--max-recursion-depth=50"],
+    );
+    assert_eq!(output.stdout, "55\n");
+    assert_eq!(output.status, 0);
+}
+
+#[test]
+fn recursion_over_the_limit_is_a_runtime_error() {
+    let output = run_with_flags(
+        &format!("{}println(sum(100));\n", SUM),
+        &["--max-recursion-depth=50"],
+    );
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: stack overflow; recursion through 'sum' went more than 50 calls deep (line 1)\n"
+    );
+    assert_eq!(output.status, 1);
+}
+
+#[test]
+fn recursion_limit_can_be_given_after_a_space() {
+    let output = run_with_flags(
+        &format!("{}println(sum(10));\n", SUM),
+        &["--max-recursion-depth", "5"],
+    );
+    assert_eq!(
+        output.stderr,
+        "Runtime Error: stack overflow; recursion through 'sum' went more than 5 calls deep (line 1)\n"
+    );
+}
+
+#[test]
+fn invalid_recursion_limit_is_an_error() {
+    for (flags, found) in [
+        (&["--max-recursion-depth=abc"][..], "abc"),
+        (&["--max-recursion-depth=0"][..], "0"),
+        (&["--max-recursion-depth", "x"][..], "x"),
+        (&["--max-recursion-depth"][..], ""),
+    ] {
+        let errors = compile_errors_with_flags(&format!("{}println(sum(10));\n", SUM), flags);
+        let message = format!(
+            "--max-recursion-depth must be a whole number above 0, found '{}'",
+            found
+        );
+        assert!(errors.contains(&message), "{}", errors);
+    }
+}